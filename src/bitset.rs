@@ -0,0 +1,182 @@
+use std::hash::{Hash, Hasher};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of small non-negative integers stored as a growable bit vector. Trailing zero
+/// words are ignored, so sets compare equal regardless of how far they have grown.
+#[derive(Debug, Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(bits.div_ceil(BITS)),
+        }
+    }
+
+    fn significant(&self) -> &[u64] {
+        let len = self
+            .words
+            .iter()
+            .rposition(|&w| w != 0)
+            .map_or(0, |i| i + 1);
+        &self.words[..len]
+    }
+
+    fn grow(&mut self, words: usize) {
+        if self.words.len() < words {
+            self.words.resize(words, 0);
+        }
+    }
+
+    /// Adds `bit`, returning whether it was newly inserted.
+    pub fn insert(&mut self, bit: usize) -> bool {
+        self.grow(bit / BITS + 1);
+        let mask = 1 << (bit % BITS);
+        let inserted = self.words[bit / BITS] & mask == 0;
+        self.words[bit / BITS] |= mask;
+        inserted
+    }
+
+    /// Removes `bit`, returning whether it was present.
+    pub fn remove(&mut self, bit: usize) -> bool {
+        let present = self.contains(bit);
+        if present {
+            self.words[bit / BITS] &= !(1 << (bit % BITS));
+        }
+        present
+    }
+
+    pub fn toggle(&mut self, bit: usize) {
+        self.grow(bit / BITS + 1);
+        self.words[bit / BITS] ^= 1 << (bit % BITS);
+    }
+
+    pub fn contains(&self, bit: usize) -> bool {
+        self.words
+            .get(bit / BITS)
+            .is_some_and(|w| w >> (bit % BITS) & 1 == 1)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// The largest member, if any.
+    pub fn max(&self) -> Option<usize> {
+        let words = self.significant();
+        let last = *words.last()?;
+        Some((words.len() - 1) * BITS + (BITS - 1 - last.leading_zeros() as usize))
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// Members in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * BITS + bit)
+            })
+        })
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.significant() == other.significant()
+    }
+}
+
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.significant().hash(state);
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for bit in iter {
+            set.insert(bit);
+        }
+        set
+    }
+}
+
+impl BitXorAssign<&BitSet> for BitSet {
+    fn bitxor_assign(&mut self, other: &BitSet) {
+        self.grow(other.words.len());
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a ^= b;
+        }
+    }
+}
+
+impl BitOrAssign<&BitSet> for BitSet {
+    fn bitor_assign(&mut self, other: &BitSet) {
+        self.grow(other.words.len());
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+}
+
+impl BitAndAssign<&BitSet> for BitSet {
+    fn bitand_assign(&mut self, other: &BitSet) {
+        self.words.truncate(other.words.len());
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= b;
+        }
+    }
+}
+
+impl BitXor for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        result ^= other;
+        result
+    }
+}
+
+impl BitOr for &BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        result |= other;
+        result
+    }
+}
+
+impl BitAnd for &BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        result &= other;
+        result
+    }
+}
@@ -0,0 +1,92 @@
+use crate::report::Report;
+use std::fmt::Display;
+use std::time::Duration;
+
+const BAR_WIDTH: usize = 50;
+const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+pub struct Chart<'a> {
+    report: &'a Report,
+    log_scale: bool,
+}
+
+impl<'a> Chart<'a> {
+    pub fn new(report: &'a Report, log_scale: bool) -> Self {
+        Self { report, log_scale }
+    }
+
+    fn max_time(&self) -> Duration {
+        self.report
+            .results
+            .iter()
+            .map(|r| r.total_time)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn scale(&self, duration: Duration) -> f64 {
+        let micros = duration.as_secs_f64() * 1_000_000.0;
+        if self.log_scale {
+            (micros + 1.0).log10()
+        } else {
+            micros
+        }
+    }
+
+    fn bar(&self, duration: Duration) -> String {
+        let max = self.scale(self.max_time());
+        if max <= 0.0 {
+            return String::new();
+        }
+
+        let eighths = (self.scale(duration) / max * (BAR_WIDTH * 8) as f64).round() as usize;
+        "█".repeat(eighths / 8) + EIGHTHS[eighths % 8]
+    }
+
+    fn percentage(&self, duration: Duration) -> f64 {
+        let total = self.report.total_time();
+        if total.is_zero() {
+            0.0
+        } else {
+            duration.as_secs_f64() / total.as_secs_f64() * 100.0
+        }
+    }
+
+    fn write_line(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        label: &str,
+        duration: Duration,
+    ) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<8} {:<width$} {:>10} {:>6.1}%",
+            label,
+            self.bar(duration),
+            Report::format_duration(duration),
+            self.percentage(duration),
+            width = BAR_WIDTH
+        )
+    }
+}
+
+impl Display for Chart<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for result in &self.report.results {
+            self.write_line(f, &format!("Day {:02}", result.day), result.total_time)?;
+
+            for part in 1..=2 {
+                if let (_, Some(time)) = result.part(part) {
+                    self.write_line(f, &format!("  Part {}", part), time)?;
+                }
+            }
+        }
+
+        writeln!(
+            f,
+            "\nTotal: {}{}",
+            Report::format_duration(self.report.total_time()),
+            if self.log_scale { " (log scale)" } else { "" }
+        )
+    }
+}
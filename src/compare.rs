@@ -0,0 +1,116 @@
+use crate::days::DayResult;
+use crate::report::Report;
+use comfy_table::Table;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::time::Duration;
+
+pub struct Comparison<'a> {
+    old: &'a Report,
+    new: &'a Report,
+}
+
+impl<'a> Comparison<'a> {
+    pub fn new(old: &'a Report, new: &'a Report) -> Self {
+        Self { old, new }
+    }
+
+    fn days(&self) -> BTreeSet<u8> {
+        self.old
+            .results
+            .iter()
+            .chain(&self.new.results)
+            .map(|r| r.day)
+            .collect()
+    }
+
+    fn find(report: &Report, day: u8) -> Option<&DayResult> {
+        report.results.iter().find(|r| r.day == day)
+    }
+
+    fn part(report: &Report, day: u8, part: u8) -> (Option<&str>, Option<Duration>) {
+        Self::find(report, day).map_or((None, None), |r| r.part(part))
+    }
+
+    pub fn answer_differences(&self) -> usize {
+        self.days()
+            .into_iter()
+            .flat_map(|day| [(day, 1), (day, 2)])
+            .filter(|&(day, part)| {
+                let (old, _) = Self::part(self.old, day, part);
+                let (new, _) = Self::part(self.new, day, part);
+                old.is_some() && new.is_some() && old != new
+            })
+            .count()
+    }
+
+    fn format_time(time: Option<Duration>) -> String {
+        time.map(Report::format_duration).unwrap_or("-".to_string())
+    }
+
+    fn format_speedup(old: Option<Duration>, new: Option<Duration>) -> String {
+        match (old, new) {
+            (Some(old), Some(new)) if !new.is_zero() => {
+                format!("{:.2}x", old.as_secs_f64() / new.as_secs_f64())
+            }
+            _ => "-".to_string(),
+        }
+    }
+
+    fn format_answer(old: Option<&str>, new: Option<&str>) -> String {
+        match (old, new) {
+            (Some(old), Some(new)) if old != new => format!("✗ {} → {}", old, new),
+            (Some(answer), Some(_)) => answer.to_string(),
+            (None, Some(new)) => format!("+ {}", new),
+            (Some(old), None) => format!("- {}", old),
+            (None, None) => "-".to_string(),
+        }
+    }
+
+    fn format_change(old: Duration, new: Duration) -> String {
+        if old.is_zero() {
+            return "-".to_string();
+        }
+        let change = (new.as_secs_f64() - old.as_secs_f64()) / old.as_secs_f64() * 100.0;
+        format!("{:+.1}%", change)
+    }
+}
+
+impl Display for Comparison<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = Table::new();
+        table.set_header(vec![
+            "Day", "Part", "Old Time", "New Time", "Speedup", "Answer",
+        ]);
+
+        for day in self.days() {
+            for part in 1..=2 {
+                let (old_answer, old_time) = Self::part(self.old, day, part);
+                let (new_answer, new_time) = Self::part(self.new, day, part);
+
+                table.add_row(vec![
+                    format!("{:02}", day),
+                    part.to_string(),
+                    Self::format_time(old_time),
+                    Self::format_time(new_time),
+                    Self::format_speedup(old_time, new_time),
+                    Self::format_answer(old_answer, new_answer),
+                ]);
+            }
+        }
+
+        let old_total = self.old.total_time();
+        let new_total = self.new.total_time();
+
+        writeln!(f, "{}", table)?;
+        writeln!(
+            f,
+            "\nTotal: {} → {} ({}, {}) | {} answer difference(s)",
+            Report::format_duration(old_total),
+            Report::format_duration(new_total),
+            Self::format_change(old_total, new_total),
+            Self::format_speedup(Some(old_total), Some(new_total)),
+            self.answer_differences()
+        )
+    }
+}
@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
 use crate::days::Solution;
+use crate::error::ParseError;
+use crate::parse;
 
 const INITIAL_DIAL: i32 = 50;
 
@@ -14,13 +16,13 @@ enum Direction {
 }
 
 impl FromStr for Direction {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "L" => Ok(Direction::Left),
             "R" => Ok(Direction::Right),
-            _ => Err(format!("Invalid direction: {s}")),
+            _ => Err(ParseError::new(format!("Invalid direction: {s}")).at_column(1)),
         }
     }
 }
@@ -31,14 +33,15 @@ struct Rotation {
 }
 
 impl FromStr for Rotation {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (dir_str, dist_str) = s.split_at(1);
+        let split = s.chars().next().map_or(0, char::len_utf8);
+        let (dir_str, dist_str) = s.split_at(split);
         let direction = dir_str.parse()?;
         let distance = dist_str
             .parse()
-            .map_err(|e| format!("Invalid distance: {e}"))?;
+            .map_err(|e| ParseError::new(format!("Invalid distance: {e}")).at_column(split + 1))?;
         Ok(Rotation {
             direction,
             distance,
@@ -47,15 +50,12 @@ impl FromStr for Rotation {
 }
 
 impl FromStr for Day01 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let rotations = input
-            .lines()
-            .map(|line| line.parse::<Rotation>())
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok(Day01 { rotations })
+        Ok(Day01 {
+            rotations: parse::lines(input)?,
+        })
     }
 }
 
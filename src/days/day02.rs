@@ -1,14 +1,12 @@
 use std::str::FromStr;
 
 use crate::days::Solution;
+use crate::error::ParseError;
+use crate::interval::Interval;
+use crate::parse;
 
 pub struct Day02 {
-    ranges: Vec<Range>,
-}
-
-struct Range {
-    start: i64,
-    end: i64,
+    ranges: Vec<Interval>,
 }
 
 trait TwiceRepeating {
@@ -44,59 +42,32 @@ impl AtLeastTwiceRepeating for i64 {
     }
 }
 
-impl Range {
-    fn sum_twice_repeating_ids(&self) -> i64 {
-        (self.start..=self.end).filter(|i| i.repeats_twice()).sum()
-    }
-
-    fn sum_at_least_twice_repeating_ids(&self) -> i64 {
-        (self.start..=self.end)
-            .filter(|i| i.repeats_at_least_twice())
+impl Day02 {
+    fn sum_ids(&self, predicate: impl Fn(&i64) -> bool) -> i64 {
+        self.ranges
+            .iter()
+            .flat_map(Interval::iter)
+            .filter(predicate)
             .sum()
     }
 }
 
-impl FromStr for Range {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start_str, end_str) = s.split_once('-').ok_or("Invalid range")?;
-        let start = start_str
-            .parse()
-            .map_err(|e| format!("Invalid start: {e}"))?;
-        let end = end_str.parse().map_err(|e| format!("Invalid end: {e}"))?;
-        Ok(Range { start, end })
-    }
-}
-
 impl FromStr for Day02 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let ranges = input
-            .trim()
-            .split(',')
-            .map(|range_str| range_str.parse::<Range>())
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok(Day02 { ranges })
+        Ok(Day02 {
+            ranges: parse::lines_with(input.trim(), |line| parse::list(line, ','))?.concat(),
+        })
     }
 }
 
 impl Solution for Day02 {
     fn part1(&self) -> String {
-        self.ranges
-            .iter()
-            .map(|range| range.sum_twice_repeating_ids())
-            .sum::<i64>()
-            .to_string()
+        self.sum_ids(|i| i.repeats_twice()).to_string()
     }
 
     fn part2(&self) -> String {
-        self.ranges
-            .iter()
-            .map(|range| range.sum_at_least_twice_repeating_ids())
-            .sum::<i64>()
-            .to_string()
+        self.sum_ids(|i| i.repeats_at_least_twice()).to_string()
     }
 }
@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
 use crate::days::Solution;
+use crate::error::ParseError;
+use crate::parse;
 
 pub struct Day03 {
     banks: Vec<Bank>,
@@ -11,15 +13,15 @@ struct Bank {
 }
 
 impl FromStr for Bank {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let batteries = s
-            .chars()
-            .map(|b| {
+            .char_indices()
+            .map(|(i, b)| {
                 b.to_digit(10)
                     .map(|d| d as u64)
-                    .ok_or_else(|| format!("Invalid digit: {b}"))
+                    .ok_or_else(|| ParseError::new(format!("Invalid digit: {b}")).at_column(i + 1))
             })
             .collect::<Result<Vec<_>, _>>()?;
         Ok(Bank { batteries })
@@ -49,15 +51,12 @@ impl Bank {
 }
 
 impl FromStr for Day03 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let banks = input
-            .lines()
-            .map(|line| line.parse::<Bank>())
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok(Day03 { banks })
+        Ok(Day03 {
+            banks: parse::lines(input)?,
+        })
     }
 }
 
@@ -1,60 +1,48 @@
 use std::str::FromStr;
 
 use crate::days::Solution;
+use crate::error::ParseError;
 use crate::matrix::Matrix;
+use crate::matrix::bitgrid::BitGrid;
 
 const ROLL: char = '@';
-const EMPTY: char = '.';
 
 pub struct Day04 {
-    matrix: Matrix<char>,
+    rolls: BitGrid,
 }
 
-fn is_accessible(matrix: &Matrix<char>, row: usize, col: usize) -> bool {
-    matrix.get(row, col) == Some(&ROLL)
-        && matrix
-            .neighbors(row, col)
-            .filter(|&(r, c)| matrix.get(r, c) == Some(&ROLL))
-            .count()
-            < 4
+fn accessible(rolls: &BitGrid) -> BitGrid {
+    rolls & &rolls.neighbor_count_where(|count| count < 4)
 }
 
 impl FromStr for Day04 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let matrix = Matrix::new(input.lines().map(|line| line.chars().collect()).collect());
-        Ok(Day04 { matrix })
+        let matrix: Matrix<char> = input.parse()?;
+        let rolls = BitGrid::from_matrix(&matrix, |&c| c == ROLL);
+        Ok(Day04 { rolls })
     }
 }
 
 impl Solution for Day04 {
     fn part1(&self) -> String {
-        self.matrix
-            .iter_coords()
-            .filter(|&(row, col)| is_accessible(&self.matrix, row, col))
-            .count()
-            .to_string()
+        accessible(&self.rolls).count_ones().to_string()
     }
 
     fn part2(&self) -> String {
-        let mut matrix = self.matrix.clone();
+        let mut rolls = self.rolls.clone();
         let mut count = 0;
 
         loop {
-            let to_remove: Vec<_> = matrix
-                .iter_coords()
-                .filter(|&(row, col)| is_accessible(&matrix, row, col))
-                .collect();
+            let to_remove = accessible(&rolls);
 
             if to_remove.is_empty() {
                 break;
             }
 
-            for (row, col) in to_remove {
-                *matrix.get_mut(row, col).unwrap() = EMPTY;
-                count += 1;
-            }
+            count += to_remove.count_ones();
+            rolls = rolls.and_not(&to_remove);
         }
 
         count.to_string()
@@ -1,7 +1,8 @@
 use std::str::FromStr;
 
 use crate::days::Solution;
-use crate::matrix::Matrix;
+use crate::error::ParseError;
+use crate::matrix::{Matrix, Ragged};
 
 pub struct Day06 {
     matrix: Matrix<char>,
@@ -38,32 +39,12 @@ impl Problem {
     }
 }
 
-impl FromStr for Matrix<char> {
-    type Err = String;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<&str> = input.lines().collect();
-        let max_len = lines.iter().map(|l| l.len()).max().unwrap_or(0);
-
-        let data: Vec<Vec<char>> = lines
-            .iter()
-            .map(|l| {
-                let mut chars: Vec<char> = l.chars().collect();
-                chars.resize(max_len, ' ');
-                chars
-            })
-            .collect();
-
-        Ok(Matrix::new(data))
-    }
-}
-
 impl FromStr for Day06 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         Ok(Day06 {
-            matrix: input.parse()?,
+            matrix: Matrix::parse_with(input, Ragged::Pad(' '), |c| c)?,
         })
     }
 }
@@ -88,9 +69,7 @@ impl Day06 {
         let mut current = Vec::new();
 
         for col in col_iter {
-            let column: Vec<char> = (0..self.matrix.rows)
-                .map(|row| *self.matrix.get(row, col).unwrap())
-                .collect();
+            let column: Vec<char> = self.matrix.column(col).copied().collect();
 
             if column.iter().all(|&c| c == ' ') {
                 if !current.is_empty() {
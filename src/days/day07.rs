@@ -1,74 +1,67 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::VecDeque;
 use std::str::FromStr;
 
 use crate::days::Solution;
-use crate::matrix::Matrix;
+use crate::error::ParseError;
+use crate::matrix::bitgrid::BitGrid;
+use crate::matrix::pos::{Dir, Pos};
+use crate::matrix::{Matrix, Ragged};
+use crate::memo::Memo;
 
 const START: char = 'S';
 const EMPTY: char = '.';
 const SPLITTER: char = '^';
-const BEAM: char = '|';
 
 pub struct Day07 {
     matrix: Matrix<char>,
-    start: (usize, usize),
+    start: Pos,
 }
 
 impl FromStr for Day07 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let matrix = Matrix::new(input.lines().map(|line| line.chars().collect()).collect());
+        let (matrix, markers) = Matrix::parse_with_markers(input, Ragged::Reject, &[START], |c| c)?;
 
-        let start = matrix
-            .iter_coords()
-            .find(|&(row, col)| matrix.get(row, col) == Some(&START))
-            .unwrap();
+        let start = Pos::from(*markers[&START].first().ok_or("No start position")?);
         Ok(Day07 { matrix, start })
     }
 }
 
 impl Day07 {
     fn simulate(&self) -> usize {
-        let mut matrix = self.matrix.clone();
+        let matrix = &self.matrix;
         let mut split_count = 0;
-        let mut visited: HashSet<(usize, usize)> = HashSet::new();
+        let mut visited = BitGrid::new(matrix.rows, matrix.cols);
 
-        let mut beams: VecDeque<(usize, usize)> = VecDeque::new();
+        let mut beams: VecDeque<Pos> = VecDeque::new();
         beams.push_back(self.start);
-        visited.insert(self.start);
+        visited.insert(self.start.row, self.start.col);
 
-        while let Some((mut row, col)) = beams.pop_front() {
+        while let Some(mut pos) = beams.pop_front() {
             loop {
-                if let Some(cell) = matrix.get_mut(row, col)
-                    && *cell == EMPTY
-                {
-                    *cell = BEAM;
-                }
-
-                visited.insert((row, col));
+                visited.insert(pos.row, pos.col);
 
-                let next_row = row + 1;
-                if next_row >= matrix.rows {
+                let Some(next) = matrix.step(pos, Dir::DOWN) else {
                     break;
-                }
+                };
 
-                match matrix.get(next_row, col) {
-                    Some(&SPLITTER) => {
+                match matrix[next] {
+                    SPLITTER => {
                         split_count += 1;
 
-                        if col > 0 && visited.insert((next_row, col - 1)) {
-                            beams.push_back((next_row, col - 1));
-                        }
-
-                        if col + 1 < matrix.cols && visited.insert((next_row, col + 1)) {
-                            beams.push_back((next_row, col + 1));
+                        for dir in [Dir::LEFT, Dir::RIGHT] {
+                            if let Some(side) = matrix.step(next, dir)
+                                && visited.insert(side.row, side.col)
+                            {
+                                beams.push_back(side);
+                            }
                         }
 
                         break;
                     }
-                    Some(&EMPTY) | Some(&BEAM) => {
-                        row = next_row;
+                    EMPTY => {
+                        pos = next;
                     }
                     _ => {
                         break;
@@ -81,54 +74,39 @@ impl Day07 {
     }
 
     fn simulate_quantum(&self) -> usize {
-        let mut memo: HashMap<(usize, usize), usize> = HashMap::new();
+        let cols = self.matrix.cols;
+        let mut memo = Memo::dense(self.matrix.rows * cols, move |pos: &Pos| {
+            pos.row * cols + pos.col
+        });
         self.count_timelines(self.start, &mut memo)
     }
 
-    fn count_timelines(
-        &self,
-        cell: (usize, usize),
-        memo: &mut HashMap<(usize, usize), usize>,
-    ) -> usize {
-        if let Some(&count) = memo.get(&cell) {
-            return count;
-        }
-
-        let mut row = cell.0;
-        let col = cell.1;
-
-        loop {
-            let next_row = row + 1;
-            if next_row >= self.matrix.rows {
-                memo.insert(cell, 1);
-                return 1;
-            }
+    fn count_timelines(&self, cell: Pos, memo: &mut Memo<Pos, usize>) -> usize {
+        memo.get_or_compute(cell, |memo, &cell| {
+            let mut pos = cell;
 
-            match self.matrix.get(next_row, col) {
-                Some(&SPLITTER) => {
-                    let left = if col > 0 {
-                        self.count_timelines((next_row, col - 1), memo)
-                    } else {
-                        0
-                    };
-                    let right = if col + 1 < self.matrix.cols {
-                        self.count_timelines((next_row, col + 1), memo)
-                    } else {
-                        0
-                    };
-                    let total = left + right;
-                    memo.insert(cell, total);
-                    return total;
-                }
-                Some(&EMPTY) | Some(&START) => {
-                    row = next_row;
-                }
-                _ => {
-                    memo.insert(cell, 1);
+            loop {
+                let Some(next) = self.matrix.step(pos, Dir::DOWN) else {
                     return 1;
+                };
+
+                match self.matrix[next] {
+                    SPLITTER => {
+                        return [Dir::LEFT, Dir::RIGHT]
+                            .into_iter()
+                            .filter_map(|dir| self.matrix.step(next, dir))
+                            .map(|side| self.count_timelines(side, memo))
+                            .sum();
+                    }
+                    EMPTY | START => {
+                        pos = next;
+                    }
+                    _ => {
+                        return 1;
+                    }
                 }
             }
-        }
+        })
     }
 }
 
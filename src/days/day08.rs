@@ -1,124 +1,97 @@
-use std::collections::HashMap;
 use std::str::FromStr;
 
 use crate::days::Solution;
+use crate::error::ParseError;
+use crate::kdtree::KdTree;
+use crate::parse;
+use crate::point::Point3;
+use crate::union_find::UnionFind;
 
 const MAX_CONNECTIONS: usize = 1000;
+const INITIAL_NEIGHBORS: usize = 8;
 
-struct Point {
-    x: i64,
-    y: i64,
-    z: i64,
-}
-
-impl Point {
-    fn distance(&self, other: &Point) -> i64 {
-        (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)
-    }
+pub struct Day08 {
+    points: Vec<Point3>,
 }
 
-impl FromStr for Point {
-    type Err = String;
+impl FromStr for Day08 {
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut iter = s.split(',').map(|s| s.parse().unwrap());
-        let x = iter.next().unwrap();
-        let y = iter.next().unwrap();
-        let z = iter.next().unwrap();
-        Ok(Point { x, y, z })
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Day08 {
+            points: parse::lines(input)?,
+        })
     }
 }
 
-struct UnionFind {
-    parent: Vec<usize>,
-    rank: Vec<usize>,
-    size: Vec<usize>,
-}
-
-impl UnionFind {
-    fn new(n: usize) -> Self {
-        Self {
-            parent: (0..n).collect(),
-            rank: vec![0; n],
-            size: vec![1; n],
-        }
-    }
-
-    fn find(&mut self, x: usize) -> usize {
-        if self.parent[x] != x {
-            self.parent[x] = self.find(self.parent[x]);
-        }
-        self.parent[x]
-    }
-
-    fn union(&mut self, x: usize, y: usize) -> bool {
-        let root_x = self.find(x);
-        let root_y = self.find(y);
-
-        if root_x == root_y {
-            return false;
-        }
-
-        if self.rank[root_x] < self.rank[root_y] {
-            self.parent[root_x] = root_y;
-            self.size[root_y] += self.size[root_x];
-        } else if self.rank[root_x] > self.rank[root_y] {
-            self.parent[root_y] = root_x;
-            self.size[root_x] += self.size[root_y];
-        } else {
-            self.parent[root_y] = root_x;
-            self.rank[root_x] += 1;
-            self.size[root_x] += self.size[root_y];
-        }
-
-        true
+impl Day08 {
+    /// Edges from every point to its `k` nearest neighbours, deduplicated and sorted.
+    fn neighbor_edges(&self, tree: &KdTree<3>, k: usize) -> Vec<(i64, usize, usize)> {
+        let mut edges: Vec<(i64, usize, usize)> = self
+            .points
+            .iter()
+            .enumerate()
+            .flat_map(|(i, point)| {
+                tree.nearest(point, k + 1)
+                    .into_iter()
+                    .filter(move |&(j, _)| j != i)
+                    .map(move |(j, distance)| (distance, i.min(j), i.max(j)))
+            })
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
     }
 
-    fn sizes(&mut self) -> Vec<usize> {
-        let mut size_map = HashMap::new();
-        for i in 0..self.parent.len() {
-            let root = self.find(i);
-            *size_map.entry(root).or_insert(0) += 1;
-        }
-        size_map.values().cloned().collect()
+    /// Every edge no longer than `radius_sq`, sorted by length and then by endpoints.
+    fn edges_within(&self, tree: &KdTree<3>, radius_sq: i64) -> Vec<(i64, usize, usize)> {
+        let mut edges: Vec<(i64, usize, usize)> = self
+            .points
+            .iter()
+            .enumerate()
+            .flat_map(|(i, point)| {
+                tree.within(point, radius_sq)
+                    .into_iter()
+                    .filter(move |&(j, _)| j > i)
+                    .map(move |(j, distance)| (distance, i, j))
+            })
+            .collect();
+        edges.sort_unstable();
+        edges
     }
-}
-
-pub struct Day08 {
-    points: Vec<Point>,
-}
 
-impl FromStr for Day08 {
-    type Err = String;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let points = input
-            .lines()
-            .map(|line| line.parse())
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Day08 { points })
-    }
-}
+    /// Adds edges in order until everything is connected, returning the last one used.
+    fn connect_all(&self, edges: &[(i64, usize, usize)]) -> Option<(i64, usize, usize)> {
+        let mut uf = UnionFind::new(self.points.len());
 
-impl Day08 {
-    fn sorted_edges(&self) -> Vec<(usize, usize, i64)> {
-        let mut edges = Vec::new();
-        for i in 0..self.points.len() {
-            for j in i + 1..self.points.len() {
-                edges.push((i, j, self.points[i].distance(&self.points[j])));
+        for &(distance, i, j) in edges {
+            if uf.union(i, j) && uf.component_count() == 1 {
+                return Some((distance, i, j));
             }
         }
-        edges.sort_by_key(|(_, _, distance)| *distance);
-        edges
+
+        None
     }
 }
 
 impl Solution for Day08 {
     fn part1(&self) -> String {
-        let edges = self.sorted_edges();
-        let mut uf = UnionFind::new(self.points.len());
+        let tree = KdTree::new(&self.points);
+        let wanted =
+            MAX_CONNECTIONS.min(self.points.len() * self.points.len().saturating_sub(1) / 2);
+
+        // Any length covering enough nearest-neighbour edges bounds the shortest ones overall.
+        let mut k = INITIAL_NEIGHBORS;
+        let radius_sq = loop {
+            let candidates = self.neighbor_edges(&tree, k);
+            if candidates.len() >= wanted || k >= self.points.len() {
+                break candidates.get(wanted.saturating_sub(1)).map_or(0, |e| e.0);
+            }
+            k *= 2;
+        };
 
-        for (i, j, _) in edges.iter().take(MAX_CONNECTIONS) {
+        let mut uf = UnionFind::new(self.points.len());
+        for (_, i, j) in self.edges_within(&tree, radius_sq).iter().take(wanted) {
             uf.union(*i, *j);
         }
 
@@ -129,22 +102,23 @@ impl Solution for Day08 {
     }
 
     fn part2(&self) -> String {
-        let edges = self.sorted_edges();
-        let mut uf = UnionFind::new(self.points.len());
-        let mut last_connection = (0, 0);
-
-        for (i, j, _) in &edges {
-            if uf.union(*i, *j) {
-                last_connection = (*i, *j);
+        let tree = KdTree::new(&self.points);
 
-                let root = uf.find(0);
-                if uf.size[root] == self.points.len() {
-                    break;
-                }
+        // The nearest-neighbour graph gives an upper bound on the last connection needed.
+        let mut k = INITIAL_NEIGHBORS;
+        let radius_sq = loop {
+            if let Some((distance, _, _)) = self.connect_all(&self.neighbor_edges(&tree, k)) {
+                break distance;
             }
-        }
+            if k >= self.points.len() {
+                return String::new();
+            }
+            k *= 2;
+        };
 
-        let (i, j) = last_connection;
-        (self.points[i].x * self.points[j].x).to_string()
+        match self.connect_all(&self.edges_within(&tree, radius_sq)) {
+            Some((_, i, j)) => (self.points[i][0] * self.points[j][0]).to_string(),
+            None => String::new(),
+        }
     }
 }
@@ -1,46 +1,29 @@
 use std::str::FromStr;
 
 use crate::days::Solution;
-
-struct Tile {
-    x: i64,
-    y: i64,
-}
-
-impl FromStr for Tile {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s.split_once(',').unwrap();
-        Ok(Tile {
-            x: x.parse().unwrap(),
-            y: y.parse().unwrap(),
-        })
-    }
-}
+use crate::error::ParseError;
+use crate::geometry::Polygon;
+use crate::parse;
+use crate::point::Point2;
 
 pub struct Day09 {
-    tiles: Vec<Tile>,
+    tiles: Vec<Point2>,
 }
 
 impl FromStr for Day09 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let tiles = input
-            .lines()
-            .map(|line| line.parse::<Tile>().unwrap())
-            .collect();
-        Ok(Day09 { tiles })
+        Ok(Day09 {
+            tiles: parse::lines(input)?,
+        })
     }
 }
 
-impl Tile {
-    fn area(&self, other: &Tile) -> i64 {
-        let width = (self.x - other.x).abs() + 1;
-        let height = (self.y - other.y).abs() + 1;
-        width * height
-    }
+fn area(a: &Point2, b: &Point2) -> i64 {
+    let width = (a[0] - b[0]).abs() + 1;
+    let height = (a[1] - b[1]).abs() + 1;
+    width * height
 }
 
 impl Solution for Day09 {
@@ -48,13 +31,28 @@ impl Solution for Day09 {
         let mut max_area = 0;
         for i in 0..self.tiles.len() {
             for j in i + 1..self.tiles.len() {
-                max_area = max_area.max(self.tiles[i].area(&self.tiles[j]));
+                max_area = max_area.max(area(&self.tiles[i], &self.tiles[j]));
             }
         }
         max_area.to_string()
     }
 
     fn part2(&self) -> String {
-        "".to_string()
+        let polygon = Polygon::new(self.tiles.clone());
+        if !polygon.is_rectilinear() {
+            return "".to_string();
+        }
+
+        let raster = polygon.rasterize();
+        let mut max_area = 0;
+        for i in 0..self.tiles.len() {
+            for j in i + 1..self.tiles.len() {
+                let area = area(&self.tiles[i], &self.tiles[j]);
+                if area > max_area && raster.contains_rect(self.tiles[i], self.tiles[j]) {
+                    max_area = area;
+                }
+            }
+        }
+        max_area.to_string()
     }
 }
@@ -1,108 +1,126 @@
 use std::ops::Deref;
 use std::str::FromStr;
 
+use crate::bitset::BitSet;
 use crate::days::Solution;
+use crate::error::ParseError;
+use crate::gf2::Gf2Matrix;
+use crate::ilp::IntegerProgram;
+use crate::parse;
 
-struct Button(u64);
+struct Button(BitSet);
 
 impl Deref for Button {
-    type Target = u64;
+    type Target = BitSet;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-struct Target(u64);
-
-impl Deref for Target {
-    type Target = u64;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl FromStr for Button {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim_start_matches('(').trim_end_matches(')');
-
-        let mut button_bits = 0u64;
-        for num_str in s.split(',') {
-            if let Ok(idx) = num_str.trim().parse::<usize>() {
-                button_bits |= 1 << idx;
-            }
-        }
-        Ok(Button(button_bits))
-    }
+struct Target {
+    lights: BitSet,
+    len: usize,
 }
 
 impl FromStr for Target {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut target = 0u64;
+        let mut lights = BitSet::with_capacity(s.len());
         for (i, c) in s.chars().enumerate() {
-            if c == '#' {
-                target |= 1 << i;
+            match c {
+                '#' => {
+                    lights.insert(i);
+                }
+                '.' => {}
+                _ => {
+                    return Err(ParseError::new(format!("Invalid light: {c}")).at_column(i + 1));
+                }
             }
         }
-        Ok(Target(target))
+        Ok(Target {
+            lights,
+            len: s.chars().count(),
+        })
     }
 }
 
 struct Machine {
     target: Target,
     buttons: Vec<Button>,
+    joltages: Vec<i64>,
 }
 
 impl FromStr for Machine {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bracket_start = s.find('[').ok_or("No [")?;
-        let bracket_end = s.find(']').ok_or("No ]")?;
-        let pattern = &s[bracket_start + 1..bracket_end];
-
-        let target = pattern.parse::<Target>()?;
-
-        let rest = &s[bracket_end + 1..];
-        let brace_start = rest.find('{').ok_or("No {")?;
-        let button_section = &rest[..brace_start];
-
-        let buttons: Vec<Button> = button_section
-            .split(' ')
-            .map(|s| s.parse::<Button>())
+        let (offset, pattern) = *parse::bracketed(s, '[', ']')?
+            .first()
+            .ok_or("Missing [lights]")?;
+        let target = pattern.parse::<Target>().map_err(|e| e.shifted(offset))?;
+
+        let buttons = parse::bracketed(s, '(', ')')?
+            .into_iter()
+            .map(|(offset, s)| {
+                // Range-check before building the bitset, which grows to fit its largest bit.
+                let lights: Vec<usize> = parse::list(s, ',').map_err(|e| e.shifted(offset))?;
+                if let Some(i) = lights.iter().position(|&light| light >= target.len) {
+                    let item = s.split(',').nth(i).unwrap_or_default();
+                    let column = s.split(',').take(i).map(|p| p.len() + 1).sum::<usize>()
+                        + (item.len() - item.trim_start().len());
+                    return Err(ParseError::new(format!(
+                        "Light {} out of range for {} lights",
+                        lights[i], target.len
+                    ))
+                    .at_column(column + 1)
+                    .shifted(offset));
+                }
+                Ok(Button(lights.into_iter().collect()))
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(Machine { target, buttons })
+        let (offset, joltages) = *parse::bracketed(s, '{', '}')?
+            .first()
+            .ok_or("Missing {joltages}")?;
+        let joltages: Vec<i64> = parse::list(joltages, ',').map_err(|e| e.shifted(offset))?;
+        if joltages.len() != target.len {
+            return Err(ParseError::new(format!(
+                "Expected {} joltages, found {}",
+                target.len,
+                joltages.len()
+            ))
+            .shifted(offset));
+        }
+
+        Ok(Machine {
+            target,
+            buttons,
+            joltages,
+        })
     }
 }
 
 impl Machine {
-    fn min_presses_for_lights(&self) -> usize {
-        let n = self.buttons.len();
-        let mut min = usize::MAX;
-
-        for mask in 0..(1u64 << n) {
-            let mut state = 0u64;
-            let mut presses = 0;
-
-            for (i, button) in self.buttons.iter().enumerate() {
-                if mask & (1 << i) != 0 {
-                    state ^= **button;
-                    presses += 1;
-                }
-            }
+    /// `None` if no combination of buttons lights the target pattern.
+    fn min_presses_for_lights(&self) -> Option<usize> {
+        let columns: Vec<BitSet> = self.buttons.iter().map(|b| b.0.clone()).collect();
 
-            if state == *self.target {
-                min = min.min(presses);
-            }
-        }
+        Gf2Matrix::from_columns(&columns, self.target.len)
+            .solve(&self.target.lights)
+            .map(|solution| solution.min_weight().count_ones())
+    }
+
+    /// `None` if no non-negative number of presses reaches the joltages.
+    fn min_presses_for_joltages(&self) -> Option<i64> {
+        let a = (0..self.joltages.len())
+            .map(|i| self.buttons.iter().map(|b| b.contains(i) as i64).collect())
+            .collect();
 
-        min
+        IntegerProgram::new(a, self.joltages.clone())
+            .minimize_total()
+            .map(|(presses, _)| presses)
     }
 }
 
@@ -111,14 +129,12 @@ pub struct Day10 {
 }
 
 impl FromStr for Day10 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let machines = input
-            .lines()
-            .map(|line| line.parse())
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Day10 { machines })
+        Ok(Day10 {
+            machines: parse::lines(input)?,
+        })
     }
 }
 
@@ -127,11 +143,15 @@ impl Solution for Day10 {
         self.machines
             .iter()
             .map(|m| m.min_presses_for_lights())
-            .sum::<usize>()
-            .to_string()
+            .sum::<Option<usize>>()
+            .map_or(String::new(), |presses| presses.to_string())
     }
 
     fn part2(&self) -> String {
-        "".to_string()
+        self.machines
+            .iter()
+            .map(|m| m.min_presses_for_joltages())
+            .sum::<Option<i64>>()
+            .map_or(String::new(), |presses| presses.to_string())
     }
 }
@@ -1,3 +1,6 @@
+use crate::error::ParseError;
+use crate::memo::{self, CacheStats};
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::str::FromStr;
 use std::time::{Duration, Instant};
@@ -13,12 +16,12 @@ mod day08;
 mod day09;
 mod day10;
 
-pub trait Solution: FromStr {
+pub trait Solution: FromStr<Err = ParseError> {
     fn part1(&self) -> String;
     fn part2(&self) -> String;
 }
 
-type PartResult = (Option<String>, Option<Duration>);
+type PartResult = (Option<String>, Option<Duration>, Option<CacheStats>);
 type SolutionResult = (PartResult, PartResult);
 
 pub fn read_input(day: u8) -> Result<String, std::io::Error> {
@@ -36,6 +39,7 @@ macro_rules! time_it {
     }};
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct DayResult {
     pub day: u8,
     pub input_load_time: Duration,
@@ -44,19 +48,28 @@ pub struct DayResult {
     pub part2_answer: Option<String>,
     pub part2_time: Option<Duration>,
     pub total_time: Duration,
+    #[serde(default)]
+    pub part1_cache: Option<CacheStats>,
+    #[serde(default)]
+    pub part2_cache: Option<CacheStats>,
 }
 
 impl DayResult {
-    pub fn run(day: u8) -> Option<Self> {
+    /// Runs a day's solution, or returns `None` if it has no input or no solution.
+    pub fn run(day: u8) -> Option<Result<Self, ParseError>> {
         let (input_result, input_load_time) = time_it!(read_input(day));
         let input = input_result.ok()?;
 
-        let ((part1_answer, part1_time), (part2_answer, part2_time)) = run_solution(day, &input)?;
+        let ((part1_answer, part1_time, part1_cache), (part2_answer, part2_time, part2_cache)) =
+            match run_solution(day, &input)? {
+                Ok(parts) => parts,
+                Err(e) => return Some(Err(e.in_day(day))),
+            };
 
         let total_time =
             input_load_time + part1_time.unwrap_or_default() + part2_time.unwrap_or_default();
 
-        Some(DayResult {
+        Some(Ok(DayResult {
             day,
             input_load_time,
             part1_answer,
@@ -64,15 +77,39 @@ impl DayResult {
             part2_answer,
             part2_time,
             total_time,
-        })
+            part1_cache,
+            part2_cache,
+        }))
+    }
+
+    pub fn cache(&self, part: u8) -> Option<CacheStats> {
+        match part {
+            1 => self.part1_cache,
+            2 => self.part2_cache,
+            _ => None,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> (Option<&str>, Option<Duration>) {
+        match part {
+            1 => (self.part1_answer.as_deref(), self.part1_time),
+            2 => (self.part2_answer.as_deref(), self.part2_time),
+            _ => (None, None),
+        }
     }
 }
 
 macro_rules! run_day {
     ($input:expr, $day:ty) => {{
-        let solution = $input.parse::<$day>().ok()?;
+        let solution = match $input.parse::<$day>() {
+            Ok(solution) => solution,
+            Err(e) => return Some(Err(e)),
+        };
+
+        memo::take_recorded();
 
         let (part1_result, part1_time) = time_it!(solution.part1());
+        let part1_cache = memo::take_recorded();
         let (part1_answer, part1_time) = if part1_result.is_empty() {
             (None, None)
         } else {
@@ -80,17 +117,21 @@ macro_rules! run_day {
         };
 
         let (part2_result, part2_time) = time_it!(solution.part2());
+        let part2_cache = memo::take_recorded();
         let (part2_answer, part2_time) = if part2_result.is_empty() {
             (None, None)
         } else {
             (Some(part2_result), Some(part2_time))
         };
 
-        Some(((part1_answer, part1_time), (part2_answer, part2_time)))
+        Some(Ok((
+            (part1_answer, part1_time, part1_cache),
+            (part2_answer, part2_time, part2_cache),
+        )))
     }};
 }
 
-fn run_solution(day: u8, input: &str) -> Option<SolutionResult> {
+fn run_solution(day: u8, input: &str) -> Option<Result<SolutionResult, ParseError>> {
     match day {
         1 => run_day!(input, day01::Day01),
         2 => run_day!(input, day02::Day02),
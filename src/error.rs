@@ -0,0 +1,124 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::num::{ParseFloatError, ParseIntError};
+
+/// An input parsing failure, located as precisely as the parser could manage.
+/// Lines and columns are 1-based; columns count bytes within the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub snippet: Option<String>,
+    pub cause: String,
+}
+
+impl ParseError {
+    pub fn new(cause: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: None,
+            column: None,
+            snippet: None,
+            cause: cause.into(),
+        }
+    }
+
+    /// Sets the column unless a more precise one is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Moves an error raised while parsing a substring to the coordinates of the
+    /// enclosing text, where the substring starts `offset` bytes in.
+    pub fn shifted(mut self, offset: usize) -> Self {
+        self.column = Some(offset + self.column.unwrap_or(1));
+        self
+    }
+
+    /// Sets the line and its text unless they are already known.
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        self.line.get_or_insert(line);
+        self.snippet.get_or_insert_with(|| text.to_string());
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    fn location(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(day) = self.day {
+            parts.push(format!("Day {:02}", day));
+        }
+        if let Some(line) = self.line {
+            parts.push(format!("line {}", line));
+        }
+        if let Some(column) = self.column {
+            parts.push(format!("column {}", column));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Renders the location and cause, followed by the offending line with a caret under
+/// the bad column when both are known.
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = self.location();
+        if location.is_empty() {
+            write!(f, "{}", self.cause)?;
+        } else {
+            write!(f, "{}: {}", location, self.cause)?;
+        }
+
+        if let Some(snippet) = &self.snippet {
+            let gutter = self.line.map_or(String::new(), |line| line.to_string());
+            write!(f, "\n{} | {}", gutter, snippet)?;
+
+            if let Some(column) = self.column {
+                let caret = snippet
+                    .get(..column.saturating_sub(1))
+                    .map_or(column.saturating_sub(1), |prefix| prefix.chars().count());
+                write!(f, "\n{:w$} | {:c$}^", "", "", w = gutter.len(), c = caret)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<String> for ParseError {
+    fn from(cause: String) -> Self {
+        Self::new(cause)
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(cause: &str) -> Self {
+        Self::new(cause)
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(e: ParseIntError) -> Self {
+        Self::new(e.to_string())
+    }
+}
+
+impl From<ParseFloatError> for ParseError {
+    fn from(e: ParseFloatError) -> Self {
+        Self::new(e.to_string())
+    }
+}
+
+impl From<Infallible> for ParseError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
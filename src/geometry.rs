@@ -0,0 +1,236 @@
+use crate::matrix::Matrix;
+use crate::point::Point2;
+
+#[allow(dead_code)]
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+/// A simple polygon on the integer lattice, given by its vertices in order. The last
+/// vertex connects back to the first.
+pub struct Polygon {
+    vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2>) -> Self {
+        Self { vertices }
+    }
+
+    #[allow(dead_code)]
+    pub fn vertices(&self) -> &[Point2] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    pub fn is_rectilinear(&self) -> bool {
+        self.edges().all(|(a, b)| a[0] == b[0] || a[1] == b[1])
+    }
+
+    /// Twice the enclosed area, by the shoelace formula.
+    #[allow(dead_code)]
+    pub fn double_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a[0] * b[1] - b[0] * a[1])
+            .sum::<i64>()
+            .abs()
+    }
+
+    #[allow(dead_code)]
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+
+    /// Lattice points lying on the boundary.
+    #[allow(dead_code)]
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd(b[0] - a[0], b[1] - a[1]))
+            .sum()
+    }
+
+    /// Lattice points strictly inside, by Pick's theorem.
+    #[allow(dead_code)]
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// Lattice points inside or on the boundary.
+    #[allow(dead_code)]
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    #[allow(dead_code)]
+    pub fn on_boundary(&self, p: Point2) -> bool {
+        self.edges().any(|(a, b)| {
+            let cross = (b - a)[0] * (p - a)[1] - (b - a)[1] * (p - a)[0];
+            cross == 0
+                && p[0] >= a[0].min(b[0])
+                && p[0] <= a[0].max(b[0])
+                && p[1] >= a[1].min(b[1])
+                && p[1] <= a[1].max(b[1])
+        })
+    }
+
+    /// Whether `p` is inside the polygon or on its boundary.
+    #[allow(dead_code)]
+    pub fn contains(&self, p: Point2) -> bool {
+        if self.on_boundary(p) {
+            return true;
+        }
+
+        // Cast a ray towards +x, counting edges crossing it with half-open spans in y.
+        let crossings = self
+            .edges()
+            .filter(|&(a, b)| (a[1] > p[1]) != (b[1] > p[1]))
+            .filter(|&(a, b)| {
+                let (a, b) = if a[1] < b[1] { (a, b) } else { (b, a) };
+                (b - a)[0] * (p - a)[1] - (b - a)[1] * (p - a)[0] > 0
+            })
+            .count();
+        crossings % 2 == 1
+    }
+
+    /// Whether every lattice point of the axis-aligned rectangle with corners `a` and `b`
+    /// is inside the polygon. For many queries, rasterize once instead.
+    #[allow(dead_code)]
+    pub fn contains_rect(&self, a: Point2, b: Point2) -> bool {
+        self.rasterize().contains_rect(a, b)
+    }
+
+    /// Rasterizes a rectilinear polygon onto its compressed coordinates.
+    pub fn rasterize(&self) -> Raster {
+        Raster::new(self)
+    }
+}
+
+/// Sorted distinct coordinates splitting an axis into ranges. Each vertex coordinate gets
+/// a range of its own, so membership is uniform across every range of a rectilinear polygon.
+pub struct Compression {
+    values: Vec<i64>,
+}
+
+impl Compression {
+    pub fn new(coords: impl IntoIterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = coords.into_iter().flat_map(|v| [v, v + 1]).collect();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The range containing `v`, clamped to the first range for values before it.
+    pub fn index(&self, v: i64) -> usize {
+        self.values.partition_point(|&x| x <= v).saturating_sub(1)
+    }
+
+    /// The first coordinate of range `index`.
+    pub fn value(&self, index: usize) -> i64 {
+        self.values[index]
+    }
+}
+
+/// A rectilinear polygon sampled on compressed coordinates, with prefix sums for
+/// constant-time rectangle queries.
+pub struct Raster {
+    xs: Compression,
+    ys: Compression,
+    #[allow(dead_code)]
+    inside: Matrix<bool>,
+    prefix: Matrix<u32>,
+}
+
+impl Raster {
+    fn new(polygon: &Polygon) -> Self {
+        assert!(polygon.is_rectilinear(), "Polygon is not rectilinear");
+
+        let xs = Compression::new(polygon.vertices.iter().map(|p| p[0]));
+        let ys = Compression::new(polygon.vertices.iter().map(|p| p[1]));
+        let mut inside = Matrix::filled(ys.len(), xs.len(), false);
+
+        for (a, b) in polygon.edges() {
+            let (r1, r2) = (ys.index(a[1]), ys.index(b[1]));
+            let (c1, c2) = (xs.index(a[0]), xs.index(b[0]));
+            for row in r1.min(r2)..=r1.max(r2) {
+                for col in c1.min(c2)..=c1.max(c2) {
+                    inside[(row, col)] = true;
+                }
+            }
+        }
+
+        for row in 0..ys.len() {
+            let y = ys.value(row);
+            let mut crossings: Vec<usize> = polygon
+                .edges()
+                .filter(|&(a, b)| a[0] == b[0] && a[1].min(b[1]) <= y && y < a[1].max(b[1]))
+                .map(|(a, _)| xs.index(a[0]))
+                .collect();
+            crossings.sort_unstable();
+
+            let mut crossed = 0;
+            for col in 0..xs.len() {
+                while crossed < crossings.len() && crossings[crossed] < col {
+                    crossed += 1;
+                }
+                if (crossings.len() - crossed) % 2 == 1 {
+                    inside[(row, col)] = true;
+                }
+            }
+        }
+
+        let mut prefix = Matrix::filled(ys.len() + 1, xs.len() + 1, 0);
+        for row in 0..ys.len() {
+            for col in 0..xs.len() {
+                prefix[(row + 1, col + 1)] =
+                    inside[(row, col)] as u32 + prefix[(row, col + 1)] + prefix[(row + 1, col)]
+                        - prefix[(row, col)];
+            }
+        }
+
+        Self {
+            xs,
+            ys,
+            inside,
+            prefix,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, p: Point2) -> bool {
+        if self.xs.is_empty() || p[0] < self.xs.value(0) || p[1] < self.ys.value(0) {
+            return false;
+        }
+
+        self.inside[(self.ys.index(p[1]), self.xs.index(p[0]))]
+    }
+
+    /// Whether every lattice point of the axis-aligned rectangle with corners `a` and `b`
+    /// is inside the polygon.
+    pub fn contains_rect(&self, a: Point2, b: Point2) -> bool {
+        if self.xs.is_empty()
+            || a[0].min(b[0]) < self.xs.value(0)
+            || a[1].min(b[1]) < self.ys.value(0)
+        {
+            return false;
+        }
+
+        let (c1, c2) = (self.xs.index(a[0].min(b[0])), self.xs.index(a[0].max(b[0])));
+        let (r1, r2) = (self.ys.index(a[1].min(b[1])), self.ys.index(a[1].max(b[1])));
+        let count = self.prefix[(r2 + 1, c2 + 1)] + self.prefix[(r1, c1)]
+            - self.prefix[(r1, c2 + 1)]
+            - self.prefix[(r2 + 1, c1)];
+        count as usize == (r2 - r1 + 1) * (c2 - c1 + 1)
+    }
+}
@@ -0,0 +1,262 @@
+use crate::bitset::BitSet;
+
+/// A matrix over GF(2), each row stored as a bitset of its columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gf2Matrix {
+    rows: Vec<BitSet>,
+    cols: usize,
+}
+
+/// The solutions of `A x = b`: `particular` xor any combination of `nullspace`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gf2Solution {
+    pub particular: BitSet,
+    pub nullspace: Vec<BitSet>,
+}
+
+impl Gf2Matrix {
+    pub fn new(rows: Vec<BitSet>, cols: usize) -> Self {
+        assert!(
+            rows.iter().all(|row| row.max().is_none_or(|c| c < cols)),
+            "Gf2Matrix row has entries beyond {cols} columns"
+        );
+        Self { rows, cols }
+    }
+
+    /// Builds a matrix from column bitsets, where bit `r` of column `c` is entry `(r, c)`.
+    pub fn from_columns(columns: &[BitSet], rows: usize) -> Self {
+        let rows = (0..rows)
+            .map(|r| {
+                columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, column)| column.contains(r))
+                    .map(|(c, _)| c)
+                    .collect()
+            })
+            .collect();
+        Self::new(rows, columns.len())
+    }
+
+    #[allow(dead_code)]
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        self.rows[row].contains(col)
+    }
+
+    /// Reduces to row echelon form in place, applying the same row operations to `rhs`.
+    /// Returns the pivot column of each leading row.
+    fn eliminate(&mut self, rhs: &mut [bool]) -> Vec<usize> {
+        let mut pivots = Vec::new();
+
+        for col in 0..self.cols {
+            let rank = pivots.len();
+            let Some(pivot) = (rank..self.rows.len()).find(|&r| self.get(r, col)) else {
+                continue;
+            };
+
+            self.rows.swap(rank, pivot);
+            rhs.swap(rank, pivot);
+
+            let pivot_row = self.rows[rank].clone();
+            for r in 0..self.rows.len() {
+                if r != rank && self.get(r, col) {
+                    self.rows[r] ^= &pivot_row;
+                    rhs[r] ^= rhs[rank];
+                }
+            }
+
+            pivots.push(col);
+        }
+
+        pivots
+    }
+
+    #[allow(dead_code)]
+    pub fn rank(&self) -> usize {
+        let mut rhs = vec![false; self.rows.len()];
+        self.clone().eliminate(&mut rhs).len()
+    }
+
+    /// Solves `A x = rhs`, or returns `None` if the system is inconsistent.
+    pub fn solve(&self, rhs: &BitSet) -> Option<Gf2Solution> {
+        if rhs.max().is_some_and(|r| r >= self.rows.len()) {
+            return None;
+        }
+
+        let mut reduced = self.clone();
+        let mut rhs: Vec<bool> = (0..self.rows.len()).map(|r| rhs.contains(r)).collect();
+        let pivots = reduced.eliminate(&mut rhs);
+
+        if rhs[pivots.len()..].iter().any(|&b| b) {
+            return None;
+        }
+
+        let particular = pivots
+            .iter()
+            .enumerate()
+            .filter(|&(r, _)| rhs[r])
+            .map(|(_, &col)| col)
+            .collect();
+
+        let nullspace = (0..self.cols)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                pivots
+                    .iter()
+                    .enumerate()
+                    .filter(|&(r, _)| reduced.get(r, free))
+                    .map(|(_, &col)| col)
+                    .chain([free])
+                    .collect()
+            })
+            .collect();
+
+        Some(Gf2Solution {
+            particular,
+            nullspace,
+        })
+    }
+}
+
+impl Gf2Solution {
+    /// Every solution, visiting the nullspace in Gray code order. The counter is a bitset,
+    /// so any nullity works, though there are `2^nullity` solutions.
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = BitSet> + '_ {
+        let mut next = Some(self.particular.clone());
+        let mut counter = BitSet::new();
+        std::iter::from_fn(move || {
+            let x = next.take()?;
+
+            // Incrementing the counter clears its trailing ones; the bit it then sets is
+            // the nullspace vector to flip.
+            let flip = (0..).take_while(|&i| counter.remove(i)).count();
+            counter.insert(flip);
+            if let Some(vector) = self.nullspace.get(flip) {
+                next = Some(&x ^ vector);
+            }
+
+            Some(x)
+        })
+    }
+
+    /// The solution with the fewest set bits. Relies on the shape `solve` produces: each
+    /// nullspace vector is the only one touching its free column, so the number of
+    /// vectors chosen bounds the weight from below and prunes the search.
+    pub fn min_weight(&self) -> BitSet {
+        let mut current = self.particular.clone();
+        let mut best = current.clone();
+        self.search_min_weight(0, 0, &mut current, &mut best);
+        best
+    }
+
+    fn search_min_weight(
+        &self,
+        index: usize,
+        chosen: usize,
+        current: &mut BitSet,
+        best: &mut BitSet,
+    ) {
+        if chosen >= best.count_ones() {
+            return;
+        }
+        let Some(vector) = self.nullspace.get(index) else {
+            if current.count_ones() < best.count_ones() {
+                *best = current.clone();
+            }
+            return;
+        };
+
+        self.search_min_weight(index + 1, chosen, current, best);
+        *current ^= vector;
+        self.search_min_weight(index + 1, chosen + 1, current, best);
+        *current ^= vector;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(bits: &[usize]) -> BitSet {
+        bits.iter().copied().collect()
+    }
+
+    fn matrix(rows: &[&[usize]], cols: usize) -> Gf2Matrix {
+        Gf2Matrix::new(rows.iter().map(|row| bits(row)).collect(), cols)
+    }
+
+    fn apply(matrix: &Gf2Matrix, x: &BitSet) -> BitSet {
+        (0..matrix.rows())
+            .filter(|&r| (&matrix.rows[r] & x).count_ones() % 2 == 1)
+            .collect()
+    }
+
+    fn check_min_weight(matrix: &Gf2Matrix, rhs: &BitSet) {
+        let solution = matrix.solve(rhs).unwrap();
+        assert_eq!(solution.nullspace.len(), matrix.cols() - matrix.rank());
+
+        let all: Vec<BitSet> = solution.iter().collect();
+        assert_eq!(all.len(), 1 << solution.nullspace.len());
+        assert!(all.iter().all(|x| apply(matrix, x) == *rhs));
+
+        let brute = all.iter().map(BitSet::count_ones).min().unwrap();
+        let best = solution.min_weight();
+        assert_eq!(apply(matrix, &best), *rhs);
+        assert_eq!(best.count_ones(), brute);
+    }
+
+    #[test]
+    fn min_weight_matches_exhaustive_search() {
+        check_min_weight(&matrix(&[&[0, 1, 2], &[1, 3]], 4), &bits(&[0]));
+        check_min_weight(
+            &matrix(&[&[0, 2, 4], &[1, 2, 3], &[3, 4, 5]], 6),
+            &bits(&[0, 1, 2]),
+        );
+
+        // A cheap linear congruential generator covers a spread of small systems.
+        let mut seed = 12345u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+        for _ in 0..50 {
+            let (rows, cols) = (1 + next() % 4, 1 + next() % 7);
+            let rows: Vec<BitSet> = (0..rows)
+                .map(|_| (0..cols).filter(|_| next() % 2 == 0).collect())
+                .collect();
+            let matrix = Gf2Matrix::new(rows, cols);
+            let x: BitSet = (0..cols).filter(|_| next() % 3 == 0).collect();
+            check_min_weight(&matrix, &apply(&matrix, &x));
+        }
+    }
+
+    #[test]
+    fn full_rank_system_has_one_solution() {
+        let identity = matrix(&[&[0], &[1], &[2]], 3);
+        let solution = identity.solve(&bits(&[0, 2])).unwrap();
+
+        assert!(solution.nullspace.is_empty());
+        assert_eq!(solution.iter().collect::<Vec<_>>(), vec![bits(&[0, 2])]);
+        assert_eq!(solution.min_weight(), bits(&[0, 2]));
+    }
+
+    #[test]
+    fn inconsistent_system_has_no_solution() {
+        let matrix = matrix(&[&[0, 1], &[0, 1], &[2]], 3);
+        assert_eq!(matrix.solve(&bits(&[0])), None);
+        assert_eq!(matrix.solve(&bits(&[3])), None);
+        assert!(matrix.solve(&bits(&[0, 1])).is_some());
+    }
+}
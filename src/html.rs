@@ -0,0 +1,219 @@
+use crate::days::DayResult;
+use crate::markup::escape;
+use crate::report::Report;
+use crate::verify::Status;
+use std::fmt::{Display, Write};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const BAR_WIDTH: f64 = 160.0;
+const SPARK_WIDTH: f64 = 100.0;
+const SPARK_HEIGHT: f64 = 20.0;
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; }
+th { background: #eee; cursor: pointer; user-select: none; }
+.pass { color: #2a2; } .fail { color: #c22; font-weight: bold; }
+.unverified { color: #b80; } .skipped { color: #999; }
+";
+
+const SCRIPT: &str = "
+document.querySelectorAll('th').forEach((th, col) => th.addEventListener('click', () => {
+  const body = th.closest('table').tBodies[0];
+  const asc = th.dataset.order !== 'asc';
+  th.dataset.order = asc ? 'asc' : 'desc';
+  const key = row => row.cells[col].dataset.sort ?? row.cells[col].textContent;
+  [...body.rows].sort((a, b) => {
+    const [x, y] = [key(a), key(b)];
+    const cmp = isNaN(x) || isNaN(y) ? x.localeCompare(y) : x - y;
+    return asc ? cmp : -cmp;
+  }).forEach(row => body.appendChild(row));
+}));
+";
+
+pub struct HtmlReport<'a> {
+    report: &'a Report,
+    history: Vec<Report>,
+}
+
+impl<'a> HtmlReport<'a> {
+    pub fn new(report: &'a Report, history: Vec<Report>) -> Self {
+        Self { report, history }
+    }
+
+    pub fn load_history(dir: &Path) -> Result<Vec<Report>, String> {
+        let entries =
+            fs::read_dir(dir).map_err(|e| format!("Could not read {}: {e}", dir.display()))?;
+
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        paths.iter().map(|path| Report::load(path)).collect()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|e| format!("Could not write {}: {e}", path.display()))
+    }
+
+    fn max_part_time(&self) -> Duration {
+        self.report
+            .results
+            .iter()
+            .flat_map(|r| [r.part1_time, r.part2_time])
+            .flatten()
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn time_cell(time: Option<Duration>) -> String {
+        match time {
+            Some(time) => format!(
+                "<td data-sort=\"{}\">{}</td>",
+                time.as_nanos(),
+                Report::format_duration(time)
+            ),
+            None => "<td data-sort=\"-1\">-</td>".to_string(),
+        }
+    }
+
+    fn status_cell(result: &DayResult, part: u8) -> String {
+        let status = Status::of(result, part);
+        let title = match &status {
+            Status::Fail { expected } => format!(" title=\"expected {}\"", escape(expected)),
+            _ => String::new(),
+        };
+        format!("<td class=\"{0}\"{1}>{0}</td>", status.label(), title)
+    }
+
+    fn bars(&self, result: &DayResult) -> String {
+        let max = self.max_part_time().as_secs_f64();
+        let mut svg = format!(
+            "<svg width=\"{}\" height=\"22\" xmlns=\"http://www.w3.org/2000/svg\">",
+            BAR_WIDTH
+        );
+
+        for part in 1..=2u8 {
+            if let (_, Some(time)) = result.part(part)
+                && max > 0.0
+            {
+                let width = time.as_secs_f64() / max * BAR_WIDTH;
+                let y = (part - 1) * 11;
+                let fill = if part == 1 { "#4a90d9" } else { "#d9904a" };
+                let _ = write!(
+                    svg,
+                    "<rect x=\"0\" y=\"{}\" width=\"{:.1}\" height=\"10\" fill=\"{}\">\
+                     <title>Part {}: {}</title></rect>",
+                    y,
+                    width.max(1.0),
+                    fill,
+                    part,
+                    Report::format_duration(time)
+                );
+            }
+        }
+
+        svg + "</svg>"
+    }
+
+    fn sparkline(&self, day: u8) -> String {
+        let times: Vec<f64> = self
+            .history
+            .iter()
+            .chain(std::iter::once(self.report))
+            .filter_map(|report| report.results.iter().find(|r| r.day == day))
+            .map(|r| r.total_time.as_secs_f64())
+            .collect();
+
+        if times.len() < 2 {
+            return "-".to_string();
+        }
+
+        let max = times.iter().cloned().fold(0.0, f64::max);
+        let step = SPARK_WIDTH / (times.len() - 1) as f64;
+        let points: Vec<String> = times
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| {
+                let y = if max > 0.0 {
+                    SPARK_HEIGHT - t / max * (SPARK_HEIGHT - 2.0) - 1.0
+                } else {
+                    SPARK_HEIGHT - 1.0
+                };
+                format!("{:.1},{:.1}", i as f64 * step, y)
+            })
+            .collect();
+
+        format!(
+            "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\">\
+             <polyline points=\"{}\" fill=\"none\" stroke=\"#555\" stroke-width=\"1.5\"/></svg>",
+            SPARK_WIDTH,
+            SPARK_HEIGHT,
+            points.join(" ")
+        )
+    }
+}
+
+impl Display for HtmlReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "<!DOCTYPE html>")?;
+        writeln!(f, "<html><head><meta charset=\"utf-8\">")?;
+        writeln!(f, "<title>Advent of Code 2025</title>")?;
+        writeln!(f, "<style>{}</style></head><body>", STYLE)?;
+        writeln!(f, "<h1>Advent of Code 2025</h1>")?;
+        writeln!(
+            f,
+            "<p>Total: {} stars | {}</p>",
+            self.report.total_stars(),
+            Report::format_duration(self.report.total_time())
+        )?;
+
+        writeln!(f, "<table><thead><tr>")?;
+        for header in [
+            "Day",
+            "Part 1 Answer",
+            "Part 1 Status",
+            "Part 1 Time",
+            "Part 2 Answer",
+            "Part 2 Status",
+            "Part 2 Time",
+            "Total",
+            "Timings",
+            "History",
+        ] {
+            write!(f, "<th>{}</th>", header)?;
+        }
+        writeln!(f, "</tr></thead><tbody>")?;
+
+        for result in &self.report.results {
+            write!(f, "<tr><td data-sort=\"{0}\">{0:02}</td>", result.day)?;
+            for part in 1..=2 {
+                let (answer, time) = result.part(part);
+                write!(
+                    f,
+                    "<td>{}</td>{}{}",
+                    escape(answer.unwrap_or("-")),
+                    Self::status_cell(result, part),
+                    Self::time_cell(time)
+                )?;
+            }
+            writeln!(
+                f,
+                "{}<td>{}</td><td>{}</td></tr>",
+                Self::time_cell(Some(result.total_time)),
+                self.bars(result),
+                self.sparkline(result.day)
+            )?;
+        }
+
+        writeln!(f, "</tbody></table>")?;
+        writeln!(f, "<script>{}</script>", SCRIPT)?;
+        writeln!(f, "</body></html>")
+    }
+}
@@ -0,0 +1,289 @@
+/// Linear equalities `A x = b` over non-negative integers, solved exactly by fraction-free
+/// Gauss-Jordan elimination followed by a bounded search over the free variables.
+pub struct IntegerProgram {
+    a: Vec<Vec<i64>>,
+    b: Vec<i64>,
+    upper: Vec<i64>,
+}
+
+/// A row of the reduced system: `pivot * x[col] + sum(coeffs[f] * x[f]) = rhs`, with the
+/// sum running over the free variables.
+struct Reduced {
+    col: usize,
+    pivot: i128,
+    coeffs: Vec<i128>,
+    rhs: i128,
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+impl IntegerProgram {
+    /// Upper bounds are derived from rows with only non-negative entries; any variable
+    /// left unbounded must be bounded with `with_upper_bounds` before solving.
+    pub fn new(a: Vec<Vec<i64>>, b: Vec<i64>) -> Self {
+        assert_eq!(a.len(), b.len(), "Right-hand side length mismatch");
+        let cols = a.first().map_or(0, Vec::len);
+
+        let upper = (0..cols)
+            .map(|col| {
+                a.iter()
+                    .zip(&b)
+                    .filter(|&(row, &rhs)| row[col] > 0 && rhs >= 0 && row.iter().all(|&v| v >= 0))
+                    .map(|(row, &rhs)| rhs / row[col])
+                    .min()
+                    .unwrap_or(i64::MAX)
+            })
+            .collect();
+
+        Self { a, b, upper }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_upper_bounds(mut self, upper: Vec<i64>) -> Self {
+        for (bound, limit) in self.upper.iter_mut().zip(upper) {
+            *bound = (*bound).min(limit);
+        }
+        self
+    }
+
+    pub fn variables(&self) -> usize {
+        self.upper.len()
+    }
+
+    /// Reduces the system, returning the pivot rows and free columns, or `None` if the
+    /// equalities are inconsistent.
+    fn reduce(&self) -> Option<(Vec<Reduced>, Vec<usize>)> {
+        let cols = self.variables();
+        let mut rows: Vec<Vec<i128>> = self
+            .a
+            .iter()
+            .zip(&self.b)
+            .map(|(row, &rhs)| {
+                row.iter()
+                    .map(|&v| v as i128)
+                    .chain([rhs as i128])
+                    .collect()
+            })
+            .collect();
+
+        let mut pivots = Vec::new();
+        for col in 0..cols {
+            let rank = pivots.len();
+            let Some(pivot) = (rank..rows.len()).find(|&r| rows[r][col] != 0) else {
+                continue;
+            };
+            rows.swap(rank, pivot);
+
+            for r in 0..rows.len() {
+                let factor = rows[r][col];
+                if r == rank || factor == 0 {
+                    continue;
+                }
+
+                let p = rows[rank][col];
+                let (pivot_row, row) = if r < rank {
+                    let (head, tail) = rows.split_at_mut(rank);
+                    (&tail[0], &mut head[r])
+                } else {
+                    let (head, tail) = rows.split_at_mut(r);
+                    (&head[rank], &mut tail[0])
+                };
+                for (v, &pv) in row.iter_mut().zip(pivot_row.iter()) {
+                    *v = *v * p - pv * factor;
+                }
+
+                let divisor = row.iter().fold(0, |g, &v| gcd(g, v));
+                if divisor > 1 {
+                    row.iter_mut().for_each(|v| *v /= divisor);
+                }
+            }
+
+            pivots.push(col);
+        }
+
+        if rows[pivots.len()..].iter().any(|row| row[cols] != 0) {
+            return None;
+        }
+
+        let free: Vec<usize> = (0..cols).filter(|col| !pivots.contains(col)).collect();
+        let reduced = pivots
+            .iter()
+            .zip(&rows)
+            .map(|(&col, row)| Reduced {
+                col,
+                pivot: row[col],
+                coeffs: free.iter().map(|&f| row[f]).collect(),
+                rhs: row[cols],
+            })
+            .collect();
+
+        Some((reduced, free))
+    }
+
+    /// Minimizes `cost · x`, returning the optimum and a solution attaining it, or `None`
+    /// if there is no solution or a free variable is unbounded. Branches are pruned by
+    /// cost only when every cost is non-negative.
+    pub fn minimize(&self, cost: &[i64]) -> Option<(i64, Vec<i64>)> {
+        assert_eq!(cost.len(), self.variables(), "Cost length mismatch");
+
+        let (reduced, free) = self.reduce()?;
+        if free.iter().any(|&f| self.upper[f] == i64::MAX) {
+            return None;
+        }
+
+        let mut search = Search {
+            program: self,
+            reduced: &reduced,
+            free: &free,
+            cost,
+            prune: cost.iter().all(|&c| c >= 0),
+            nonnegative: self
+                .a
+                .iter()
+                .map(|row| row.iter().all(|&v| v >= 0))
+                .collect(),
+            x: vec![0; self.variables()],
+            remaining: self.b.clone(),
+            best: None,
+        };
+        search.run(0, 0);
+        search.best
+    }
+
+    /// Minimizes the sum of all variables.
+    pub fn minimize_total(&self) -> Option<(i64, Vec<i64>)> {
+        self.minimize(&vec![1; self.variables()])
+    }
+}
+
+/// Depth-first search over the free variables, tracking what is left of `b` once the
+/// free variables assigned so far are subtracted.
+struct Search<'a> {
+    program: &'a IntegerProgram,
+    reduced: &'a [Reduced],
+    free: &'a [usize],
+    cost: &'a [i64],
+    prune: bool,
+    nonnegative: Vec<bool>,
+    x: Vec<i64>,
+    remaining: Vec<i64>,
+    best: Option<(i64, Vec<i64>)>,
+}
+
+impl Search<'_> {
+    /// The largest value `col` can take without overdrawing a row whose coefficients are
+    /// all non-negative.
+    fn bound(&self, col: usize) -> i64 {
+        self.program
+            .a
+            .iter()
+            .zip(&self.remaining)
+            .zip(&self.nonnegative)
+            .filter(|&((row, _), &nonnegative)| nonnegative && row[col] > 0)
+            .map(|((row, &remaining), _)| remaining.div_euclid(row[col]))
+            .fold(self.program.upper[col], i64::min)
+    }
+
+    fn set(&mut self, col: usize, value: i64) {
+        let delta = value - self.x[col];
+        for (remaining, row) in self.remaining.iter_mut().zip(&self.program.a) {
+            *remaining -= row[col] * delta;
+        }
+        self.x[col] = value;
+    }
+
+    fn pruned(&self, partial: i64) -> bool {
+        self.prune && self.best.as_ref().is_some_and(|(min, _)| partial >= *min)
+    }
+
+    fn run(&mut self, depth: usize, partial: i64) {
+        if let Some(&col) = self.free.get(depth) {
+            for value in 0..=self.bound(col) {
+                let partial = partial + self.cost[col] * value;
+                if self.pruned(partial) {
+                    break;
+                }
+                self.set(col, value);
+                self.run(depth + 1, partial);
+            }
+            self.set(col, 0);
+            return;
+        }
+
+        for row in self.reduced {
+            let rest: i128 = row
+                .coeffs
+                .iter()
+                .zip(self.free)
+                .map(|(&c, &f)| c * self.x[f] as i128)
+                .sum();
+            let numerator = row.rhs - rest;
+            if numerator % row.pivot != 0 {
+                return;
+            }
+
+            let value = numerator / row.pivot;
+            if value < 0 || value > self.program.upper[row.col] as i128 {
+                return;
+            }
+            self.x[row.col] = value as i64;
+        }
+
+        let total: i64 = self.x.iter().zip(self.cost).map(|(&v, &c)| v * c).sum();
+        if self.best.as_ref().is_none_or(|(min, _)| total < *min) {
+            self.best = Some((total, self.x.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inconsistent_system_is_infeasible() {
+        let program = IntegerProgram::new(vec![vec![1, 1], vec![1, 1]], vec![1, 2]);
+        assert!(program.reduce().is_none());
+        assert_eq!(program.minimize_total(), None);
+    }
+
+    #[test]
+    fn system_without_integer_solutions_is_infeasible() {
+        let program = IntegerProgram::new(vec![vec![2, 0], vec![0, 1]], vec![3, 1]);
+        assert!(program.reduce().is_some());
+        assert_eq!(program.minimize_total(), None);
+    }
+
+    #[test]
+    fn unbounded_free_variable_needs_explicit_bounds() {
+        // x0 = x1 leaves x1 free with no row to bound it.
+        let program = IntegerProgram::new(vec![vec![1, -1]], vec![0]);
+        assert_eq!(program.minimize_total(), None);
+
+        let program = program.with_upper_bounds(vec![5, 5]);
+        assert_eq!(program.minimize(&[-1, -1]), Some((-10, vec![5, 5])));
+    }
+
+    #[test]
+    fn negative_costs_disable_pruning() {
+        // x0 + x1 + x2 = 4 and x0 = x2, so x = (t, 4 - 2t, t) and the cost is -2t. The
+        // partial cost of t grows, so pruning on it would stop at t = 0.
+        let program = IntegerProgram::new(vec![vec![1, 1, 1], vec![1, 0, -1]], vec![4, 0]);
+        assert_eq!(program.minimize(&[-3, 0, 1]), Some((-4, vec![2, 0, 2])));
+        assert_eq!(program.minimize(&[0, 1, 0]), Some((0, vec![2, 0, 2])));
+    }
+
+    #[test]
+    fn optimum_can_need_nonzero_free_variables() {
+        let program = IntegerProgram::new(vec![vec![2, 4, 6], vec![4, 2, 6]], vec![12, 12]);
+
+        let (reduced, free) = program.reduce().unwrap();
+        assert_eq!(free, vec![2]);
+        // Both rows share a factor after elimination, which normalisation divides out.
+        assert!(reduced.iter().all(|row| row.pivot.abs() == 1));
+
+        assert_eq!(program.minimize_total(), Some((2, vec![0, 0, 2])));
+    }
+}
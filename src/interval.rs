@@ -0,0 +1,260 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// An inclusive range of integers, parsed from `a-b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    #[allow(dead_code)]
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start) + 1
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    pub fn contains(&self, point: i64) -> bool {
+        self.start <= point && point <= self.end
+    }
+
+    pub fn iter(&self) -> RangeInclusive<i64> {
+        self.start..=self.end
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Skip the first char so a leading minus sign isn't taken as the separator.
+        let split = s
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i)
+            .ok_or("Invalid range: missing '-'")?;
+        let (start_str, end_str) = (&s[..split], &s[split + 1..]);
+
+        let start = start_str
+            .trim()
+            .parse()
+            .map_err(|e| ParseError::new(format!("Invalid start: {e}")).at_column(1))?;
+        let end = end_str
+            .trim()
+            .parse()
+            .map_err(|e| ParseError::new(format!("Invalid end: {e}")).at_column(split + 2))?;
+        Ok(Interval { start, end })
+    }
+}
+
+/// A set of integers stored as sorted, disjoint, non-adjacent intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Total number of integers covered.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[first..last].iter().fold(interval, |acc, i| {
+            Interval::new(acc.start.min(i.start), acc.end.max(i.end))
+        });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    #[allow(dead_code)]
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let mut remaining = Vec::new();
+        for i in &self.intervals[first..last] {
+            if i.start < interval.start {
+                remaining.push(Interval::new(i.start, interval.start - 1));
+            }
+            if i.end > interval.end {
+                remaining.push(Interval::new(interval.end + 1, i.end));
+            }
+        }
+        self.intervals.splice(first..last, remaining);
+    }
+
+    fn find(&self, point: i64) -> Option<&Interval> {
+        let index = self.intervals.partition_point(|i| i.end < point);
+        self.intervals.get(index).filter(|i| i.contains(point))
+    }
+
+    pub fn contains(&self, point: i64) -> bool {
+        self.find(point).is_some()
+    }
+
+    #[allow(dead_code)]
+    pub fn contains_range(&self, interval: Interval) -> bool {
+        interval.is_empty()
+            || self
+                .find(interval.start)
+                .is_some_and(|i| interval.end <= i.end)
+    }
+
+    #[allow(dead_code)]
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for &interval in &other.intervals {
+            result.insert(interval);
+        }
+        result
+    }
+
+    #[allow(dead_code)]
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (self.intervals[a], other.intervals[b]);
+            let overlap = Interval::new(x.start.max(y.start), x.end.min(y.end));
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+            if x.end < y.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    #[allow(dead_code)]
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for &interval in &other.intervals {
+            result.remove(interval);
+        }
+        result
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        intervals.sort();
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end.saturating_add(1) => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => merged.push(interval),
+            }
+        }
+
+        IntervalSet { intervals: merged }
+    }
+}
+
+/// Parses `a-b` intervals separated by commas and/or whitespace.
+impl FromStr for IntervalSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut offset = 0;
+        s.split_inclusive(|c: char| c == ',' || c.is_whitespace())
+            .filter_map(|chunk| {
+                let start = offset;
+                offset += chunk.len();
+                let part = chunk.trim_end_matches(|c: char| c == ',' || c.is_whitespace());
+                (!part.is_empty()).then_some((start, part))
+            })
+            .map(|(start, part)| part.parse::<Interval>().map_err(|e| e.shifted(start)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(s: &str) -> IntervalSet {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn remove_splits_and_trims() {
+        let mut intervals = set("1-10 20-30");
+        intervals.remove(Interval::new(5, 22));
+        assert_eq!(intervals, set("1-4 23-30"));
+
+        intervals.remove(Interval::new(2, 3));
+        assert_eq!(intervals, set("1-1 4-4 23-30"));
+        assert_eq!(intervals.len(), 10);
+    }
+
+    #[test]
+    fn intersection_keeps_overlaps() {
+        let a = set("1-10 20-30 40-50");
+        let b = set("5-25 45-45 60-70");
+        assert_eq!(a.intersection(&b), set("5-10 20-25 45-45"));
+        assert!(a.intersection(&set("11-19")).is_empty());
+    }
+
+    #[test]
+    fn difference_removes_other_set() {
+        let a = set("1-10 20-30");
+        let b = set("0-2 8-21 30-40");
+        assert_eq!(a.difference(&b), set("3-7 22-29"));
+        assert_eq!(a.difference(&b).union(&a.intersection(&b)), a);
+    }
+}
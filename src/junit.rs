@@ -0,0 +1,91 @@
+use crate::markup::escape;
+use crate::report::Report;
+use crate::verify::Status;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+pub struct JunitReport<'a> {
+    report: &'a Report,
+}
+
+impl<'a> JunitReport<'a> {
+    pub fn new(report: &'a Report) -> Self {
+        Self { report }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|e| format!("Could not write {}: {e}", path.display()))
+    }
+
+    fn cases(&self) -> impl Iterator<Item = (u8, u8, Status)> + '_ {
+        self.report.results.iter().flat_map(|result| {
+            (1..=2).map(move |part| (result.day, part, Status::of(result, part)))
+        })
+    }
+}
+
+impl Display for JunitReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cases: Vec<_> = self.cases().collect();
+        let failures = cases
+            .iter()
+            .filter(|(_, _, status)| matches!(status, Status::Fail { .. }))
+            .count();
+        let skipped = cases
+            .iter()
+            .filter(|(_, _, status)| matches!(status, Status::Skipped | Status::Unverified))
+            .count();
+
+        writeln!(f, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(
+            f,
+            "<testsuite name=\"aoc-2025\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" time=\"{:.6}\">",
+            cases.len(),
+            failures,
+            skipped,
+            self.report.total_time().as_secs_f64()
+        )?;
+
+        for (day, part, status) in &cases {
+            let result = self.report.results.iter().find(|r| r.day == *day).unwrap();
+            let (answer, time) = result.part(*part);
+
+            write!(
+                f,
+                "  <testcase classname=\"day{:02}\" name=\"part{}\" time=\"{:.6}\"",
+                day,
+                part,
+                time.unwrap_or_default().as_secs_f64()
+            )?;
+
+            match status {
+                Status::Pass => writeln!(f, "/>")?,
+                Status::Fail { expected } => {
+                    let actual = escape(answer.unwrap_or_default());
+                    let expected = escape(expected);
+                    writeln!(f, ">")?;
+                    writeln!(
+                        f,
+                        "    <failure message=\"expected {0}, got {1}\" type=\"WrongAnswer\">expected: {0}\nactual: {1}</failure>",
+                        expected, actual
+                    )?;
+                    writeln!(f, "  </testcase>")?;
+                }
+                Status::Unverified => {
+                    writeln!(f, ">")?;
+                    writeln!(f, "    <skipped message=\"no expected answer stored\"/>")?;
+                    writeln!(f, "  </testcase>")?;
+                }
+                Status::Skipped => {
+                    writeln!(f, ">")?;
+                    writeln!(f, "    <skipped message=\"not implemented\"/>")?;
+                    writeln!(f, "  </testcase>")?;
+                }
+            }
+        }
+
+        writeln!(f, "</testsuite>")
+    }
+}
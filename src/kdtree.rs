@@ -0,0 +1,133 @@
+use std::collections::BinaryHeap;
+
+use crate::point::Point;
+
+/// A static k-d tree over a set of points, answering queries by squared Euclidean
+/// distance. The tree is stored implicitly: each subrange of `order` has its splitting
+/// point at the middle.
+pub struct KdTree<const N: usize> {
+    points: Vec<Point<N>>,
+    order: Vec<usize>,
+}
+
+impl<const N: usize> KdTree<N> {
+    pub fn new(points: &[Point<N>]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        Self::build(points, &mut order, 0);
+        Self {
+            points: points.to_vec(),
+            order,
+        }
+    }
+
+    fn build(points: &[Point<N>], order: &mut [usize], axis: usize) {
+        if order.len() <= 1 {
+            return;
+        }
+
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by_key(mid, |&i| points[i][axis]);
+
+        let (left, right) = order.split_at_mut(mid);
+        Self::build(points, left, (axis + 1) % N);
+        Self::build(points, &mut right[1..], (axis + 1) % N);
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The `k` points closest to `target` as `(index, squared distance)`, nearest first.
+    pub fn nearest(&self, target: &Point<N>, k: usize) -> Vec<(usize, i64)> {
+        let mut heap: BinaryHeap<(i64, usize)> = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search_nearest(target, k, 0, self.order.len(), 0, &mut heap);
+        }
+
+        let mut nearest: Vec<(usize, i64)> = heap.into_iter().map(|(d, i)| (i, d)).collect();
+        nearest.sort_by_key(|&(i, d)| (d, i));
+        nearest
+    }
+
+    fn search_nearest(
+        &self,
+        target: &Point<N>,
+        k: usize,
+        lo: usize,
+        hi: usize,
+        axis: usize,
+        heap: &mut BinaryHeap<(i64, usize)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        heap.push((point.squared_euclidean(target), index));
+        if heap.len() > k {
+            heap.pop();
+        }
+
+        let delta = target[axis] - point[axis];
+        let next = (axis + 1) % N;
+        let (near, far) = if delta < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.search_nearest(target, k, near.0, near.1, next, heap);
+        if heap.len() < k || delta * delta <= heap.peek().map_or(i64::MAX, |&(d, _)| d) {
+            self.search_nearest(target, k, far.0, far.1, next, heap);
+        }
+    }
+
+    /// All points within squared distance `radius_sq` of `target`, as
+    /// `(index, squared distance)` in no particular order.
+    pub fn within(&self, target: &Point<N>, radius_sq: i64) -> Vec<(usize, i64)> {
+        let mut found = Vec::new();
+        self.search_within(target, radius_sq, 0, self.order.len(), 0, &mut found);
+        found
+    }
+
+    fn search_within(
+        &self,
+        target: &Point<N>,
+        radius_sq: i64,
+        lo: usize,
+        hi: usize,
+        axis: usize,
+        found: &mut Vec<(usize, i64)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        let distance = point.squared_euclidean(target);
+        if distance <= radius_sq {
+            found.push((index, distance));
+        }
+
+        let delta = target[axis] - point[axis];
+        let next = (axis + 1) % N;
+        if delta <= 0 || delta * delta <= radius_sq {
+            self.search_within(target, radius_sq, lo, mid, next, found);
+        }
+        if delta >= 0 || delta * delta <= radius_sq {
+            self.search_within(target, radius_sq, mid + 1, hi, next, found);
+        }
+    }
+}
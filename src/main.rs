@@ -1,41 +1,211 @@
+mod bitset;
+mod chart;
+mod compare;
 mod days;
+mod error;
+mod geometry;
+mod gf2;
+mod html;
+mod ilp;
+mod interval;
+mod junit;
+mod kdtree;
+mod markup;
 mod matrix;
+mod memo;
+mod parse;
+mod point;
 mod report;
+mod union_find;
+mod verify;
 
-use report::Report;
+use chart::Chart;
+use compare::Comparison;
+use html::HtmlReport;
+use junit::JunitReport;
+use report::{Colors, Report};
 use std::env;
+use std::path::Path;
+use std::time::Duration;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} [day] [--json <file>] [--color <auto|always|never>] [--fast <ms>] [--slow <ms>] [--chart [--log]] [--html <file> [--history <dir>]] [--junit <file>]",
+        program
+    );
+    eprintln!("       {} compare <old.json> <new.json>", program);
+    eprintln!("  day: Optional day number (1-25). If omitted, runs all days.");
+    eprintln!("  --json: Also save the report as JSON to <file>.");
+    eprintln!("  --color: Color the table (default: auto, disabled by NO_COLOR or non-TTY).");
+    eprintln!("  --fast: Durations below this are green (default: 1 ms).");
+    eprintln!("  --slow: Durations from this on are red, in between yellow (default: 100 ms).");
+    eprintln!("  --chart: Show a bar chart of time per day and part instead of the table.");
+    eprintln!("  --log: Scale the chart bars logarithmically.");
+    eprintln!("  --html: Also save a self-contained HTML report to <file>.");
+    eprintln!("  --history: Directory of saved JSON reports used for HTML sparklines.");
+    eprintln!("  --junit: Also save answer verification results as JUnit XML to <file>.");
+    std::process::exit(1);
+}
 
-    match args.len() {
-        1 => {
-            let report = Report::from_day_range(1..=25);
-            println!("{}", report);
-        }
-        2 => match args[1].parse::<u8>() {
-            Ok(day) if (1..=25).contains(&day) => {
-                let report = Report::from_day_range(day..=day);
-                if report.results.is_empty() {
-                    eprintln!("Error: No solution found for day {}", day);
-                    std::process::exit(1);
+fn fail(message: String) -> ! {
+    eprintln!("Error: {}", message);
+    std::process::exit(1);
+}
+
+struct Options {
+    day: Option<u8>,
+    json_path: Option<String>,
+    html_path: Option<String>,
+    history_dir: Option<String>,
+    junit_path: Option<String>,
+    colors: Colors,
+    chart: bool,
+    log_scale: bool,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Self {
+        let mut options = Options {
+            day: None,
+            json_path: None,
+            html_path: None,
+            history_dir: None,
+            junit_path: None,
+            colors: Colors::default(),
+            chart: false,
+            log_scale: false,
+        };
+
+        let mut rest = args[1..].iter();
+        while let Some(arg) = rest.next() {
+            match arg.as_str() {
+                "--json" => {
+                    let path = rest.next().unwrap_or_else(|| usage(&args[0]));
+                    options.json_path = Some(path.clone());
                 }
-                println!("{}", report);
-            }
-            Ok(day) => {
-                eprintln!("Error: Day must be between 1 and 25, got {}", day);
-                std::process::exit(1);
+                "--html" => {
+                    let path = rest.next().unwrap_or_else(|| usage(&args[0]));
+                    options.html_path = Some(path.clone());
+                }
+                "--history" => {
+                    let dir = rest.next().unwrap_or_else(|| usage(&args[0]));
+                    options.history_dir = Some(dir.clone());
+                }
+                "--junit" => {
+                    let path = rest.next().unwrap_or_else(|| usage(&args[0]));
+                    options.junit_path = Some(path.clone());
+                }
+                "--color" => {
+                    options.colors.enabled = match rest.next().map(String::as_str) {
+                        Some("auto") => Colors::auto(),
+                        Some("always") => true,
+                        Some("never") => false,
+                        _ => usage(&args[0]),
+                    };
+                }
+                "--chart" => options.chart = true,
+                "--log" => options.log_scale = true,
+                "--fast" => options.colors.fast = Self::parse_millis(rest.next(), &args[0]),
+                "--slow" => options.colors.slow = Self::parse_millis(rest.next(), &args[0]),
+                _ if options.day.is_none() => match arg.parse::<u8>() {
+                    Ok(day) if (1..=25).contains(&day) => options.day = Some(day),
+                    Ok(day) => fail(format!("Day must be between 1 and 25, got {}", day)),
+                    Err(_) => {
+                        eprintln!("Error: Invalid day number '{}'", arg);
+                        usage(&args[0]);
+                    }
+                },
+                _ => usage(&args[0]),
             }
-            Err(_) => {
-                eprintln!("Error: Invalid day number '{}'", args[1]);
-                eprintln!("Usage: {} [day]", args[0]);
-                std::process::exit(1);
+        }
+
+        options
+    }
+
+    fn parse_millis(value: Option<&String>, program: &str) -> Duration {
+        let value = value.unwrap_or_else(|| usage(program));
+        match value.parse::<f64>() {
+            Ok(ms) if ms >= 0.0 => Duration::from_secs_f64(ms / 1_000.0),
+            _ => fail(format!("Invalid duration in milliseconds '{}'", value)),
+        }
+    }
+}
+
+fn load_report(path: &str) -> Report {
+    Report::load(Path::new(path)).unwrap_or_else(|e| fail(e))
+}
+
+fn compare(args: &[String]) {
+    if args.len() != 4 {
+        usage(&args[0]);
+    }
+
+    let old = load_report(&args[2]);
+    let new = load_report(&args[3]);
+    println!("{}", Comparison::new(&old, &new));
+}
+
+fn run(args: &[String]) {
+    let options = Options::parse(args);
+
+    let mut report = match options.day {
+        Some(day) => {
+            let report = Report::from_day_range(day..=day);
+            if report.results.is_empty() && report.errors.is_empty() {
+                fail(format!("No solution found for day {}", day));
             }
-        },
-        _ => {
-            eprintln!("Usage: {} [day]", args[0]);
-            eprintln!("  day: Optional day number (1-25). If omitted, runs all days.");
-            std::process::exit(1);
+            report
         }
+        None => Report::from_day_range(1..=25),
+    };
+    report.colors = options.colors;
+
+    for error in &report.errors {
+        eprintln!("Error: {}\n", error);
+    }
+
+    // When nothing ran, an empty table would only bury the errors above.
+    if !report.results.is_empty() || report.errors.is_empty() {
+        if options.chart {
+            println!("{}", Chart::new(&report, options.log_scale));
+        } else {
+            println!("{}", report);
+        }
+    }
+
+    if let Some(path) = options.json_path
+        && let Err(e) = report.save(Path::new(&path))
+    {
+        fail(e);
+    }
+
+    if let Some(path) = options.html_path {
+        let history = match options.history_dir {
+            Some(dir) => HtmlReport::load_history(Path::new(&dir)).unwrap_or_else(|e| fail(e)),
+            None => Vec::new(),
+        };
+
+        if let Err(e) = HtmlReport::new(&report, history).save(Path::new(&path)) {
+            fail(e);
+        }
+    }
+
+    if let Some(path) = options.junit_path
+        && let Err(e) = JunitReport::new(&report).save(Path::new(&path))
+    {
+        fail(e);
+    }
+
+    if !report.errors.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    match args.get(1).map(String::as_str) {
+        Some("compare") => compare(&args),
+        _ => run(&args),
     }
 }
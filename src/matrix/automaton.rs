@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::pos::Pos;
+use super::{Matrix, Neighborhood};
+
+/// The in-bounds neighbors of a cell, as seen by an automaton rule.
+#[allow(dead_code)]
+pub struct Neighbors<'a, T> {
+    matrix: &'a Matrix<T>,
+    pos: Pos,
+    offsets: std::slice::Iter<'a, (isize, isize)>,
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &(dr, dc) in self.offsets.by_ref() {
+            let row = self.pos.row.wrapping_add_signed(dr);
+            let col = self.pos.col.wrapping_add_signed(dc);
+            if row < self.matrix.rows && col < self.matrix.cols {
+                // SAFETY: bounds were just checked; wrapped negative offsets exceed them.
+                return Some(unsafe { self.matrix.get_unchecked(row, col) });
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Fixpoint {
+    pub generations: usize,
+    pub changed: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+/// A cellular automaton over a [`Matrix`], double-buffered so stepping does not allocate.
+#[allow(dead_code)]
+pub struct Automaton<T> {
+    current: Matrix<T>,
+    next: Matrix<T>,
+    pub generation: usize,
+}
+
+#[allow(dead_code)]
+impl<T: Clone + PartialEq> Automaton<T> {
+    pub fn new(matrix: Matrix<T>) -> Self {
+        Self {
+            next: matrix.clone(),
+            current: matrix,
+            generation: 0,
+        }
+    }
+
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.current
+    }
+
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.current
+    }
+
+    /// Advances one generation and returns the number of cells that changed.
+    pub fn step(
+        &mut self,
+        neighborhood: Neighborhood,
+        rule: impl Fn(&T, Neighbors<'_, T>) -> T,
+    ) -> usize {
+        let mut changed = 0;
+
+        let cols = self.current.cols;
+        for (i, cell) in self.current.data.iter().enumerate() {
+            let neighbors = Neighbors {
+                matrix: &self.current,
+                pos: Pos::new(i / cols, i % cols),
+                offsets: neighborhood.offsets().iter(),
+            };
+
+            let value = rule(cell, neighbors);
+            if value != *cell {
+                changed += 1;
+            }
+            self.next.data[i] = value;
+        }
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// Steps until a generation changes nothing. The final, unchanged generation is
+    /// not counted.
+    pub fn run_to_fixpoint(
+        &mut self,
+        neighborhood: Neighborhood,
+        rule: impl Fn(&T, Neighbors<'_, T>) -> T,
+    ) -> Fixpoint {
+        let mut fixpoint = Fixpoint {
+            generations: 0,
+            changed: 0,
+        };
+
+        loop {
+            let changed = self.step(neighborhood, &rule);
+            if changed == 0 {
+                return fixpoint;
+            }
+            fixpoint.generations += 1;
+            fixpoint.changed += changed;
+        }
+    }
+
+    /// Steps until a previously seen state repeats, giving up after `max_generations`.
+    /// A fixpoint is reported as a cycle with period 1.
+    pub fn find_cycle(
+        &mut self,
+        neighborhood: Neighborhood,
+        rule: impl Fn(&T, Neighbors<'_, T>) -> T,
+        max_generations: usize,
+    ) -> Option<Cycle>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen: HashMap<Matrix<T>, usize> = HashMap::new();
+        seen.insert(self.current.clone(), self.generation);
+
+        for _ in 0..max_generations {
+            self.step(neighborhood, &rule);
+            if let Some(&start) = seen.get(&self.current) {
+                return Some(Cycle {
+                    start,
+                    period: self.generation - start,
+                });
+            }
+            seen.insert(self.current.clone(), self.generation);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(alive: &bool, neighbors: Neighbors<'_, bool>) -> bool {
+        let count = neighbors.filter(|&&n| n).count();
+        count == 3 || (*alive && count == 2)
+    }
+
+    fn parse(input: &str) -> Matrix<bool> {
+        Matrix::parse_with(input, crate::matrix::Ragged::Reject, |c| c == '#').unwrap()
+    }
+
+    #[test]
+    fn blinker_cycles_with_period_two() {
+        let mut automaton = Automaton::new(parse(".....\n.....\n.###.\n.....\n....."));
+        let cycle = automaton.find_cycle(Neighborhood::Moore, life, 10).unwrap();
+
+        assert_eq!((cycle.start, cycle.period), (0, 2));
+        assert_eq!(automaton.generation, 2);
+    }
+
+    #[test]
+    fn block_is_a_fixpoint() {
+        let block = parse("....\n.##.\n.##.\n....");
+        let mut automaton = Automaton::new(block.clone());
+        let fixpoint = automaton.run_to_fixpoint(Neighborhood::Moore, life);
+
+        assert_eq!((fixpoint.generations, fixpoint.changed), (0, 0));
+        assert_eq!(automaton.into_matrix(), block);
+    }
+
+    #[test]
+    fn step_counts_changed_cells() {
+        let mut automaton = Automaton::new(parse("###"));
+        assert_eq!(automaton.step(Neighborhood::Moore, life), 2);
+        assert_eq!(automaton.matrix(), &parse(".#."));
+    }
+}
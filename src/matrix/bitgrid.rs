@@ -0,0 +1,259 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use super::{MOORE, Matrix};
+
+/// A boolean grid packed 64 cells to a word, with each row starting on a fresh word.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    data: Vec<u64>,
+    words: usize,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl BitGrid {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let words = cols.div_ceil(64);
+        Self {
+            data: vec![0; rows * words],
+            words,
+            rows,
+            cols,
+        }
+    }
+
+    pub fn from_matrix<T>(matrix: &Matrix<T>, predicate: impl Fn(&T) -> bool) -> Self {
+        let mut grid = Self::new(matrix.rows, matrix.cols);
+        for (row, col) in matrix.iter_coords() {
+            if predicate(&matrix[(row, col)]) {
+                grid.set(row, col, true);
+            }
+        }
+        grid
+    }
+
+    fn locate(&self, row: usize, col: usize) -> (usize, u64) {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds"
+        );
+        (row * self.words + col / 64, 1 << (col % 64))
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        let (word, bit) = self.locate(row, col);
+        self.data[word] & bit != 0
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        let (word, bit) = self.locate(row, col);
+        if value {
+            self.data[word] |= bit;
+        } else {
+            self.data[word] &= !bit;
+        }
+    }
+
+    /// Sets the cell and returns whether it was previously unset, like `HashSet::insert`.
+    pub fn insert(&mut self, row: usize, col: usize) -> bool {
+        let (word, bit) = self.locate(row, col);
+        let was_unset = self.data[word] & bit == 0;
+        self.data[word] |= bit;
+        was_unset
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.data.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(|&w| w == 0)
+    }
+
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.data.iter().enumerate().flat_map(move |(i, &word)| {
+            let (row, base) = (i / self.words, i % self.words * 64);
+            BitIter(word).map(move |bit| (row, base + bit))
+        })
+    }
+
+    pub fn row_words(&self, row: usize) -> &[u64] {
+        &self.data[row * self.words..(row + 1) * self.words]
+    }
+
+    fn clear_padding(&mut self) {
+        let tail = self.cols % 64;
+        if tail == 0 {
+            return;
+        }
+        let mask = (1u64 << tail) - 1;
+        for row in 0..self.rows {
+            self.data[row * self.words + self.words - 1] &= mask;
+        }
+    }
+
+    fn zip_with(&self, other: &BitGrid, op: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "grid dimensions must match"
+        );
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(&a, &b)| op(a, b))
+            .collect();
+        BitGrid { data, ..*self }
+    }
+
+    pub fn and_not(&self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    /// Shifts the contents by `cols` columns, towards higher columns when positive.
+    /// Cells shifted in from outside the grid are unset.
+    pub fn shift_cols(&self, cols: isize) -> BitGrid {
+        let mut shifted = BitGrid::new(self.rows, self.cols);
+        let amount = cols.unsigned_abs();
+        let (word_shift, bit_shift) = (amount / 64, amount % 64);
+
+        for row in 0..self.rows {
+            let src = self.row_words(row);
+            let dst = &mut shifted.data[row * self.words..(row + 1) * self.words];
+            for (i, word) in dst.iter_mut().enumerate() {
+                let at = |j: Option<usize>| j.and_then(|j| src.get(j)).copied().unwrap_or(0);
+                *word = if cols >= 0 {
+                    let hi = at(i.checked_sub(word_shift));
+                    let lo = at(i.checked_sub(word_shift + 1));
+                    if bit_shift == 0 {
+                        hi
+                    } else {
+                        hi << bit_shift | lo >> (64 - bit_shift)
+                    }
+                } else {
+                    let lo = at(Some(i + word_shift));
+                    let hi = at(Some(i + word_shift + 1));
+                    if bit_shift == 0 {
+                        lo
+                    } else {
+                        lo >> bit_shift | hi << (64 - bit_shift)
+                    }
+                };
+            }
+        }
+
+        shifted.clear_padding();
+        shifted
+    }
+
+    /// Shifts the contents by `rows` rows, towards higher rows when positive.
+    /// Rows shifted in from outside the grid are unset.
+    pub fn shift_rows(&self, rows: isize) -> BitGrid {
+        let mut shifted = BitGrid::new(self.rows, self.cols);
+        for row in 0..self.rows {
+            if let Some(src) = row.checked_add_signed(-rows).filter(|&r| r < self.rows) {
+                shifted.data[row * self.words..(row + 1) * self.words]
+                    .copy_from_slice(self.row_words(src));
+            }
+        }
+        shifted
+    }
+
+    /// The grid whose cell `(row, col)` holds this grid's cell `(row + dr, col + dc)`.
+    pub fn offset(&self, dr: isize, dc: isize) -> BitGrid {
+        self.shift_rows(-dr).shift_cols(-dc)
+    }
+
+    /// Bit-sliced count of set Moore neighbors: bit `i` of each cell's count is stored
+    /// in plane `i`.
+    pub fn neighbor_count_planes(&self) -> [BitGrid; 4] {
+        let mut planes = [(); 4].map(|_| BitGrid::new(self.rows, self.cols));
+
+        for (dr, dc) in MOORE {
+            let neighbor = self.offset(dr, dc);
+            for (i, &word) in neighbor.data.iter().enumerate() {
+                let mut carry = word;
+                for plane in planes.iter_mut() {
+                    let next = plane.data[i] & carry;
+                    plane.data[i] ^= carry;
+                    carry = next;
+                }
+            }
+        }
+
+        planes
+    }
+
+    /// Marks the cells whose number of set Moore neighbors satisfies `predicate`.
+    pub fn neighbor_count_where(&self, predicate: impl Fn(u8) -> bool) -> BitGrid {
+        let planes = self.neighbor_count_planes();
+        let mut result = BitGrid::new(self.rows, self.cols);
+
+        for count in (0..=8u8).filter(|&count| predicate(count)) {
+            for (i, word) in result.data.iter_mut().enumerate() {
+                *word |= planes.iter().enumerate().fold(!0, |acc, (bit, plane)| {
+                    if count >> bit & 1 == 1 {
+                        acc & plane.data[i]
+                    } else {
+                        acc & !plane.data[i]
+                    }
+                });
+            }
+        }
+
+        result.clear_padding();
+        result
+    }
+}
+
+struct BitIter(u64);
+
+impl Iterator for BitIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(bit)
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut inverted = BitGrid {
+            data: self.data.iter().map(|w| !w).collect(),
+            ..*self
+        };
+        inverted.clear_padding();
+        inverted
+    }
+}
@@ -0,0 +1,290 @@
+pub mod automaton;
+pub mod bitgrid;
+pub mod pos;
+pub mod render;
+pub mod search;
+pub mod sparse;
+pub mod view;
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::error::ParseError;
+use pos::{Coord, Pos};
+
+const MOORE: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+#[derive(Debug, Clone, Copy)]
+pub enum Neighborhood<'a> {
+    Moore,
+    Orthogonal,
+    Diagonal,
+    Custom(&'a [(isize, isize)]),
+}
+
+impl<'a> Neighborhood<'a> {
+    pub fn offsets(self) -> &'a [(isize, isize)] {
+        match self {
+            Neighborhood::Moore => &MOORE,
+            Neighborhood::Orthogonal => &ORTHOGONAL,
+            Neighborhood::Diagonal => &DIAGONAL,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// How grid parsing treats lines shorter or longer than the first one.
+#[derive(Debug, Clone, Copy)]
+pub enum Ragged {
+    Reject,
+    Pad(char),
+}
+
+type Markers = HashMap<char, Vec<(usize, usize)>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    data: Vec<T>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl<T> Matrix<T> {
+    pub fn new(data: Vec<Vec<T>>) -> Self {
+        let rows = data.len();
+        let cols = data.first().map_or(0, |r| r.len());
+        assert!(
+            data.iter().all(|r| r.len() == cols),
+            "all matrix rows must have the same length"
+        );
+        Self {
+            data: data.into_iter().flatten().collect(),
+            rows,
+            cols,
+        }
+    }
+
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "data length must be rows * cols");
+        Self { data, rows, cols }
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(rows, cols, vec![value; rows * cols])
+    }
+
+    pub fn parse_with(
+        input: &str,
+        ragged: Ragged,
+        cell: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseError> {
+        Self::parse_with_markers(input, ragged, &[], cell).map(|(matrix, _)| matrix)
+    }
+
+    /// Parses a grid like [`Matrix::parse_with`], also collecting the positions of every
+    /// char in `markers`.
+    pub fn parse_with_markers(
+        input: &str,
+        ragged: Ragged,
+        markers: &[char],
+        mut cell: impl FnMut(char) -> T,
+    ) -> Result<(Self, Markers), ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let cols = match ragged {
+            Ragged::Reject => lines.first().map_or(0, |l| l.chars().count()),
+            Ragged::Pad(_) => lines.iter().map(|l| l.chars().count()).max().unwrap_or(0),
+        };
+
+        let mut data = Vec::with_capacity(lines.len() * cols);
+        let mut found: Markers = markers.iter().map(|&m| (m, Vec::new())).collect();
+
+        for (row, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            let padding = match ragged {
+                Ragged::Reject if len != cols => {
+                    let column = line
+                        .char_indices()
+                        .nth(len.min(cols))
+                        .map_or(line.len(), |(i, _)| i);
+                    return Err(ParseError::new(format!(
+                        "Expected {} columns, found {}",
+                        cols, len
+                    ))
+                    .at_column(column + 1)
+                    .at_line(row + 1, line));
+                }
+                Ragged::Reject => None,
+                Ragged::Pad(c) => Some(c),
+            };
+
+            let chars = line.chars().chain(padding.into_iter().cycle()).take(cols);
+            for (col, c) in chars.enumerate() {
+                if let Some(positions) = found.get_mut(&c) {
+                    positions.push((row, col));
+                }
+                data.push(cell(c));
+            }
+        }
+
+        Ok((Self::from_vec(lines.len(), cols, data), found))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter_coords().find(|&pos| predicate(&self[pos]))
+    }
+
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter_coords().filter(move |&pos| predicate(&self[pos]))
+    }
+
+    fn offset(&self, row: usize, col: usize) -> Option<usize> {
+        (row < self.rows && col < self.cols).then_some(row * self.cols + col)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.offset(row, col).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.offset(row, col).map(|i| &mut self.data[i])
+    }
+
+    /// # Safety
+    ///
+    /// `row` must be less than `rows` and `col` less than `cols`.
+    pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
+        debug_assert!(row < self.rows && col < self.cols);
+        unsafe { self.data.get_unchecked(row * self.cols + col) }
+    }
+
+    /// # Safety
+    ///
+    /// `row` must be less than `rows` and `col` less than `cols`.
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut T {
+        debug_assert!(row < self.rows && col < self.cols);
+        unsafe { self.data.get_unchecked_mut(row * self.cols + col) }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn neighbors<P: Coord>(&self, pos: P) -> impl Iterator<Item = P> + '_ {
+        self.neighbors_in(pos, Neighborhood::Moore)
+    }
+
+    pub fn neighbors_in<'a, P: Coord>(
+        &'a self,
+        pos: P,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = P> + 'a {
+        let pos: Pos = pos.into();
+        neighborhood
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| pos.step_in(offset.into(), self).map(P::from))
+    }
+
+    pub fn wrapping_neighbors_in<'a, P: Coord>(
+        &'a self,
+        pos: P,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = P> + 'a {
+        let Pos { row, col } = pos.into();
+        let offsets = if self.data.is_empty() {
+            &[]
+        } else {
+            neighborhood.offsets()
+        };
+        offsets.iter().map(move |&(dr, dc)| {
+            let new_row = (row as isize + dr).rem_euclid(self.rows as isize) as usize;
+            let new_col = (col as isize + dc).rem_euclid(self.cols as isize) as usize;
+            P::from(Pos::new(new_row, new_col))
+        })
+    }
+
+    pub fn neighbor_values_in<'a, P: Coord>(
+        &'a self,
+        pos: P,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (P, &'a T)> + 'a {
+        self.neighbors_in(pos, neighborhood)
+            .map(move |pos| (pos, &self[Into::<Pos>::into(pos)]))
+    }
+
+    pub fn wrapping_neighbor_values_in<'a, P: Coord>(
+        &'a self,
+        pos: P,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (P, &'a T)> + 'a {
+        self.wrapping_neighbors_in(pos, neighborhood)
+            .map(move |pos| (pos, &self[Into::<Pos>::into(pos)]))
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+}
+
+impl FromStr for Matrix<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, Ragged::Reject, |c| c)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds"
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds"
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
@@ -0,0 +1,142 @@
+use std::ops::{Index, IndexMut};
+
+use super::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dir {
+    pub dr: isize,
+    pub dc: isize,
+}
+
+impl Dir {
+    pub const UP: Dir = Dir::new(-1, 0);
+    pub const DOWN: Dir = Dir::new(1, 0);
+    pub const LEFT: Dir = Dir::new(0, -1);
+    pub const RIGHT: Dir = Dir::new(0, 1);
+    pub const UP_LEFT: Dir = Dir::new(-1, -1);
+    pub const UP_RIGHT: Dir = Dir::new(-1, 1);
+    pub const DOWN_LEFT: Dir = Dir::new(1, -1);
+    pub const DOWN_RIGHT: Dir = Dir::new(1, 1);
+
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::UP, Dir::RIGHT, Dir::DOWN, Dir::LEFT];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Dir; 8] = [
+        Dir::UP,
+        Dir::UP_RIGHT,
+        Dir::RIGHT,
+        Dir::DOWN_RIGHT,
+        Dir::DOWN,
+        Dir::DOWN_LEFT,
+        Dir::LEFT,
+        Dir::UP_LEFT,
+    ];
+
+    pub const fn new(dr: isize, dc: isize) -> Self {
+        Self { dr, dc }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::new(self.dc, -self.dr)
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::new(-self.dc, self.dr)
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::new(-self.dr, -self.dc)
+    }
+}
+
+impl From<(isize, isize)> for Dir {
+    fn from((dr, dc): (isize, isize)) -> Self {
+        Self::new(dr, dc)
+    }
+}
+
+/// A grid coordinate the matrix API accepts and returns: either [`Pos`] or a
+/// `(row, col)` tuple.
+pub trait Coord: Copy + Into<Pos> + From<Pos> + 'static {}
+
+impl<P: Copy + Into<Pos> + From<Pos> + 'static> Coord for P {}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Moves one step in `dir`, or `None` if that would leave the non-negative quadrant.
+    pub fn step(self, dir: Dir) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(dir.dr)?,
+            self.col.checked_add_signed(dir.dc)?,
+        ))
+    }
+
+    /// Moves one step in `dir`, or `None` if that would leave `matrix`.
+    pub fn step_in<T>(self, dir: Dir, matrix: &Matrix<T>) -> Option<Self> {
+        self.step(dir).filter(|&pos| matrix.contains(pos))
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    pub fn chebyshev(self, other: Pos) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self::new(row, col)
+    }
+}
+
+impl From<Pos> for (usize, usize) {
+    fn from(pos: Pos) -> Self {
+        (pos.row, pos.col)
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    pub fn at(&self, pos: Pos) -> Option<&T> {
+        self.get(pos.row, pos.col)
+    }
+
+    pub fn at_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.get_mut(pos.row, pos.col)
+    }
+
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.step_in(dir, self)
+    }
+}
+
+impl<T> Index<Pos> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self[(pos.row, pos.col)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Matrix<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        &mut self[(pos.row, pos.col)]
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use super::Matrix;
+
+pub struct Render<'a, T> {
+    matrix: &'a Matrix<T>,
+    cell: Box<dyn Fn(&T) -> String + 'a>,
+    rulers: bool,
+    highlights: Vec<(&'a HashSet<(usize, usize)>, char)>,
+}
+
+impl<'a, T> Render<'a, T> {
+    pub fn new(matrix: &'a Matrix<T>, cell: impl Fn(&T) -> String + 'a) -> Self {
+        Self {
+            matrix,
+            cell: Box::new(cell),
+            rulers: false,
+            highlights: Vec::new(),
+        }
+    }
+
+    pub fn rulers(mut self, rulers: bool) -> Self {
+        self.rulers = rulers;
+        self
+    }
+
+    /// Draws `marker` over every cell in `coords`. Later overlays win over earlier ones.
+    pub fn highlight(mut self, coords: &'a HashSet<(usize, usize)>, marker: char) -> Self {
+        self.highlights.push((coords, marker));
+        self
+    }
+
+    fn cells(&self) -> Vec<String> {
+        self.matrix
+            .iter_coords()
+            .map(|pos| {
+                self.highlights
+                    .iter()
+                    .rev()
+                    .find(|(coords, _)| coords.contains(&pos))
+                    .map_or_else(|| (self.cell)(&self.matrix[pos]), |(_, m)| m.to_string())
+            })
+            .collect()
+    }
+
+    fn write_column_ruler(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        gutter: usize,
+        width: usize,
+    ) -> std::fmt::Result {
+        let last = self.matrix.cols.saturating_sub(1);
+        let digits = last.to_string().len();
+
+        if width == 1 {
+            for place in (0..digits).rev() {
+                write!(f, "{:gutter$} ", "")?;
+                for col in 0..self.matrix.cols {
+                    let digit = col / 10usize.pow(place as u32) % 10;
+                    if digit == 0 && place > 0 && col < 10usize.pow(place as u32) {
+                        write!(f, " ")?;
+                    } else {
+                        write!(f, "{}", digit)?;
+                    }
+                }
+                writeln!(f)?;
+            }
+        } else {
+            write!(f, "{:gutter$} ", "")?;
+            for col in 0..self.matrix.cols {
+                write!(f, "{:<width$}", col % 10usize.pow(width as u32))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cells = self.cells();
+        let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(1);
+        let gutter = self.matrix.rows.saturating_sub(1).to_string().len();
+
+        if self.rulers {
+            self.write_column_ruler(f, gutter, width)?;
+        }
+
+        for (row, line) in cells.chunks(self.matrix.cols.max(1)).enumerate() {
+            if self.rulers {
+                write!(f, "{:>gutter$} ", row)?;
+            }
+            for cell in line {
+                write!(f, "{:<width$}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|cell| cell.to_string()))
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn render<'a>(&'a self, cell: impl Fn(&T) -> String + 'a) -> Render<'a, T> {
+        Render::new(self, cell)
+    }
+}
@@ -0,0 +1,259 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::marker::PhantomData;
+
+use super::pos::{Coord, Pos};
+use super::{Matrix, Neighborhood};
+
+/// Distances and predecessors from a single start cell, as produced by [`Matrix::bfs`]
+/// and [`Matrix::dijkstra`]. Positions come back in the coordinate type `P` the search
+/// was started with.
+pub struct Paths<P = (usize, usize)> {
+    distances: Matrix<Option<u64>>,
+    previous: Matrix<Option<Pos>>,
+    coord: PhantomData<P>,
+}
+
+impl<P: Coord> Paths<P> {
+    fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            distances: Matrix::filled(rows, cols, None),
+            previous: Matrix::filled(rows, cols, None),
+            coord: PhantomData,
+        }
+    }
+
+    pub fn distance(&self, pos: P) -> Option<u64> {
+        let pos: Pos = pos.into();
+        self.distances.get(pos.row, pos.col).copied().flatten()
+    }
+
+    pub fn path_to(&self, pos: P) -> Option<Vec<P>> {
+        self.distance(pos)?;
+
+        let mut current: Pos = pos.into();
+        let mut path = vec![pos];
+        while let Some(prev) = self.previous[current] {
+            path.push(P::from(prev));
+            current = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = P> + '_ {
+        self.distances
+            .positions(Option::is_some)
+            .map(|pos| P::from(Pos::from(pos)))
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Unweighted shortest paths from `start`, moving from `a` to `b` only when
+    /// `passable(a, b)` holds.
+    pub fn bfs<P: Coord>(
+        &self,
+        start: P,
+        neighborhood: Neighborhood,
+        passable: impl Fn(P, P) -> bool,
+    ) -> Paths<P> {
+        let mut paths = Paths::new(self.rows, self.cols);
+        let start: Pos = start.into();
+        let mut queue = VecDeque::from([start]);
+        paths.distances[start] = Some(0);
+
+        while let Some(pos) = queue.pop_front() {
+            let distance = paths.distances[pos].unwrap();
+            for next in self.neighbors_in(pos, neighborhood) {
+                if paths.distances[next].is_none() && passable(P::from(pos), P::from(next)) {
+                    paths.distances[next] = Some(distance + 1);
+                    paths.previous[next] = Some(pos);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Weighted shortest paths from `start`, where `cost(a, b)` is the cost of moving
+    /// from `a` to `b`, or `None` if the move is not allowed.
+    pub fn dijkstra<P: Coord>(
+        &self,
+        start: P,
+        neighborhood: Neighborhood,
+        cost: impl Fn(P, P) -> Option<u64>,
+    ) -> Paths<P> {
+        let mut paths = Paths::new(self.rows, self.cols);
+        let start: Pos = start.into();
+        let mut heap = BinaryHeap::from([Reverse((0, start))]);
+        paths.distances[start] = Some(0);
+
+        while let Some(Reverse((distance, pos))) = heap.pop() {
+            if paths.distances[pos].is_some_and(|d| d < distance) {
+                continue;
+            }
+
+            for next in self.neighbors_in(pos, neighborhood) {
+                let Some(step) = cost(P::from(pos), P::from(next)) else {
+                    continue;
+                };
+                let candidate = distance + step;
+                if paths.distances[next].is_none_or(|d| candidate < d) {
+                    paths.distances[next] = Some(candidate);
+                    paths.previous[next] = Some(pos);
+                    heap.push(Reverse((candidate, next)));
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Cheapest path from `start` to `goal`. `heuristic` must never overestimate the
+    /// remaining cost for the result to be optimal.
+    pub fn astar<P: Coord>(
+        &self,
+        start: P,
+        goal: P,
+        neighborhood: Neighborhood,
+        cost: impl Fn(P, P) -> Option<u64>,
+        heuristic: impl Fn(P) -> u64,
+    ) -> Option<(u64, Vec<P>)> {
+        let mut paths = Paths::new(self.rows, self.cols);
+        let (start, goal_pos): (Pos, Pos) = (start.into(), goal.into());
+        let mut heap = BinaryHeap::from([Reverse((heuristic(P::from(start)), 0, start))]);
+        paths.distances[start] = Some(0);
+
+        while let Some(Reverse((_, distance, pos))) = heap.pop() {
+            if pos == goal_pos {
+                return Some((distance, paths.path_to(goal)?));
+            }
+            if paths.distances[pos].is_some_and(|d| d < distance) {
+                continue;
+            }
+
+            for next in self.neighbors_in(pos, neighborhood) {
+                let Some(step) = cost(P::from(pos), P::from(next)) else {
+                    continue;
+                };
+                let candidate = distance + step;
+                if paths.distances[next].is_none_or(|d| candidate < d) {
+                    paths.distances[next] = Some(candidate);
+                    paths.previous[next] = Some(pos);
+                    heap.push(Reverse((
+                        candidate + heuristic(P::from(next)),
+                        candidate,
+                        next,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn flood_fill<P: Coord>(
+        &self,
+        start: P,
+        neighborhood: Neighborhood,
+        connected: impl Fn(P, P) -> bool,
+    ) -> Vec<P> {
+        self.bfs(start, neighborhood, connected).reached().collect()
+    }
+
+    /// Labels every cell with the index of its connected component and returns the
+    /// labels along with the number of components. `connected` should be symmetric.
+    pub fn label_components<P: Coord>(
+        &self,
+        neighborhood: Neighborhood,
+        connected: impl Fn(P, P) -> bool,
+    ) -> (Matrix<usize>, usize) {
+        let mut labels: Matrix<Option<usize>> = Matrix::filled(self.rows, self.cols, None);
+        let mut count = 0;
+        let mut stack = Vec::new();
+
+        for start in self.iter_coords().map(Pos::from) {
+            if labels[start].is_some() {
+                continue;
+            }
+
+            labels[start] = Some(count);
+            stack.push(start);
+            while let Some(pos) = stack.pop() {
+                for next in self.neighbors_in(pos, neighborhood) {
+                    if labels[next].is_none() && connected(P::from(pos), P::from(next)) {
+                        labels[next] = Some(count);
+                        stack.push(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+
+        let data = labels.data.into_iter().map(Option::unwrap).collect();
+        (Matrix::from_vec(self.rows, self.cols, data), count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Matrix<char> {
+        "..#\n.##\n...".parse().unwrap()
+    }
+
+    #[test]
+    fn bfs_finds_shortest_paths_around_walls() {
+        let m = grid();
+        let paths = m.bfs((0, 0), Neighborhood::Orthogonal, |_, to: (usize, usize)| {
+            m[to] != '#'
+        });
+
+        assert_eq!(paths.distance((2, 2)), Some(4));
+        assert_eq!(paths.distance((0, 2)), None);
+        assert_eq!(
+            paths.path_to((2, 2)),
+            Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)])
+        );
+        assert_eq!(paths.reached().count(), 6);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_prefer_cheaper_detours() {
+        let m: Matrix<u64> = Matrix::new(vec![vec![1, 9, 1], vec![1, 1, 1]]);
+        let cost = |_, to: Pos| Some(m[to]);
+
+        let paths = m.dijkstra(Pos::new(0, 0), Neighborhood::Orthogonal, cost);
+        assert_eq!(paths.distance(Pos::new(0, 2)), Some(4));
+
+        let (total, path) = m
+            .astar(
+                Pos::new(0, 0),
+                Pos::new(0, 2),
+                Neighborhood::Orthogonal,
+                cost,
+                |p| p.manhattan(Pos::new(0, 2)) as u64,
+            )
+            .unwrap();
+        assert_eq!(total, 4);
+        assert_eq!(path.len(), 5);
+    }
+
+    #[test]
+    fn flood_fill_and_components_split_on_walls() {
+        let m = grid();
+        let same = |a: (usize, usize), b: (usize, usize)| m[a] == m[b];
+
+        assert_eq!(
+            m.flood_fill((0, 2), Neighborhood::Orthogonal, same).len(),
+            3
+        );
+
+        let (labels, count) = m.label_components(Neighborhood::Orthogonal, same);
+        assert_eq!(count, 2);
+        assert_eq!(labels[(0, 0)], labels[(2, 2)]);
+        assert_ne!(labels[(0, 0)], labels[(1, 1)]);
+    }
+}
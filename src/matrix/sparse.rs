@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use super::{Matrix, Neighborhood};
+
+/// An unbounded grid keyed by signed `(row, col)` coordinates, for puzzles whose
+/// occupied cells are few but spread far apart.
+#[derive(Debug, Clone, Default)]
+#[allow(dead_code)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    bounds: Option<((i64, i64), (i64, i64))>,
+}
+
+#[allow(dead_code)]
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, row: i64, col: i64) -> Option<&T> {
+        self.cells.get(&(row, col))
+    }
+
+    pub fn get_mut(&mut self, row: i64, col: i64) -> Option<&mut T> {
+        self.cells.get_mut(&(row, col))
+    }
+
+    pub fn insert(&mut self, row: i64, col: i64, value: T) -> Option<T> {
+        self.bounds = Some(Self::extend_bounds(self.bounds, (row, col)));
+        self.cells.insert((row, col), value)
+    }
+
+    pub fn remove(&mut self, row: i64, col: i64) -> Option<T> {
+        let removed = self.cells.remove(&(row, col))?;
+
+        if let Some(((min_r, min_c), (max_r, max_c))) = self.bounds
+            && (row == min_r || row == max_r || col == min_c || col == max_c)
+        {
+            self.bounds = Self::compute_bounds(self.cells.keys());
+        }
+
+        Some(removed)
+    }
+
+    fn extend_bounds(
+        bounds: Option<((i64, i64), (i64, i64))>,
+        (row, col): (i64, i64),
+    ) -> ((i64, i64), (i64, i64)) {
+        match bounds {
+            None => ((row, col), (row, col)),
+            Some(((min_r, min_c), (max_r, max_c))) => (
+                (min_r.min(row), min_c.min(col)),
+                (max_r.max(row), max_c.max(col)),
+            ),
+        }
+    }
+
+    fn compute_bounds<'a>(
+        coords: impl Iterator<Item = &'a (i64, i64)>,
+    ) -> Option<((i64, i64), (i64, i64))> {
+        coords.fold(None, |bounds, &pos| Some(Self::extend_bounds(bounds, pos)))
+    }
+
+    /// The inclusive `(min, max)` corners of the occupied region.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    pub fn neighbors(&self, row: i64, col: i64) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.neighbors_in(row, col, Neighborhood::Moore)
+    }
+
+    pub fn neighbors_in<'a>(
+        &'a self,
+        row: i64,
+        col: i64,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (i64, i64)> + 'a {
+        neighborhood
+            .offsets()
+            .iter()
+            .map(move |&(dr, dc)| (row + dr as i64, col + dc as i64))
+    }
+
+    /// Like [`SparseGrid::neighbors_in`], but only yields occupied neighbors.
+    pub fn neighbor_values_in<'a>(
+        &'a self,
+        row: i64,
+        col: i64,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = ((i64, i64), &'a T)> + 'a {
+        self.neighbors_in(row, col, neighborhood)
+            .filter_map(move |pos| self.cells.get(&pos).map(|value| (pos, value)))
+    }
+
+    /// Copies the occupied region into a dense matrix, filling gaps with `empty`.
+    /// Returns the matrix along with the `(row, col)` offset of its top-left cell.
+    pub fn to_matrix(&self, empty: T) -> (Matrix<T>, (i64, i64))
+    where
+        T: Clone,
+    {
+        let Some(((min_r, min_c), (max_r, max_c))) = self.bounds else {
+            return (Matrix::from_vec(0, 0, Vec::new()), (0, 0));
+        };
+
+        let rows = (max_r - min_r + 1) as usize;
+        let cols = (max_c - min_c + 1) as usize;
+        let mut matrix = Matrix::filled(rows, cols, empty);
+        for (&(row, col), value) in &self.cells {
+            matrix[((row - min_r) as usize, (col - min_c) as usize)] = value.clone();
+        }
+
+        (matrix, (min_r, min_c))
+    }
+
+    /// Builds a sparse grid from the cells of `matrix` for which `keep` holds, placing
+    /// the matrix's top-left cell at `offset`.
+    pub fn from_matrix(matrix: &Matrix<T>, offset: (i64, i64), keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = Self::new();
+        for (row, col) in matrix.iter_coords() {
+            let value = &matrix[(row, col)];
+            if keep(value) {
+                grid.insert(offset.0 + row as i64, offset.1 + col as i64, value.clone());
+            }
+        }
+        grid
+    }
+
+    /// Renders the occupied region row by row, passing `None` for empty cells.
+    pub fn render(&self, cell: impl Fn(Option<&T>) -> String) -> String {
+        let Some(((min_r, min_c), (max_r, max_c))) = self.bounds else {
+            return String::new();
+        };
+
+        let mut output = String::new();
+        for row in min_r..=max_r {
+            for col in min_c..=max_c {
+                output.push_str(&cell(self.get(row, col)));
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.render(|cell| cell.map_or(".".to_string(), |v| v.to_string()))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_follow_inserts_and_removals() {
+        let mut grid = SparseGrid::new();
+        grid.insert(-2, 3, 'a');
+        grid.insert(1, -1, 'b');
+        grid.insert(0, 0, 'c');
+        assert_eq!(grid.bounds(), Some(((-2, -1), (1, 3))));
+
+        assert_eq!(grid.remove(-2, 3), Some('a'));
+        assert_eq!(grid.bounds(), Some(((0, -1), (1, 0))));
+
+        grid.remove(1, -1);
+        grid.remove(0, 0);
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn round_trips_through_matrix() {
+        let matrix: Matrix<char> = ".#\n#.".parse().unwrap();
+        let grid = SparseGrid::from_matrix(&matrix, (-5, 7), |&c| c == '#');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(-5, 8), Some(&'#'));
+
+        let (dense, offset) = grid.to_matrix('.');
+        assert_eq!(offset, (-5, 7));
+        assert_eq!(dense, matrix);
+        assert_eq!(grid.to_string(), ".#\n#.\n");
+    }
+
+    #[test]
+    fn neighbor_values_skip_empty_cells() {
+        let mut grid = SparseGrid::new();
+        grid.insert(0, 0, 1);
+        grid.insert(0, 1, 2);
+        grid.insert(5, 5, 3);
+
+        assert_eq!(grid.neighbors(0, 0).count(), 8);
+        let values: Vec<_> = grid.neighbor_values_in(0, 0, Neighborhood::Moore).collect();
+        assert_eq!(values, vec![((0, 1), &2)]);
+    }
+}
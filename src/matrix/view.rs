@@ -0,0 +1,196 @@
+use std::ops::Index;
+
+use super::Matrix;
+
+/// A zero-copy transposed, rotated and/or flipped view of a [`Matrix`].
+///
+/// View coordinates are first flipped, then swapped when the view is transposed,
+/// to get the coordinates in the underlying matrix.
+#[derive(Debug)]
+pub struct View<'a, T> {
+    matrix: &'a Matrix<T>,
+    swap: bool,
+    flip_rows: bool,
+    flip_cols: bool,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    fn new(matrix: &'a Matrix<T>, swap: bool, flip_rows: bool, flip_cols: bool) -> Self {
+        let (rows, cols) = if swap {
+            (matrix.cols, matrix.rows)
+        } else {
+            (matrix.rows, matrix.cols)
+        };
+        Self {
+            matrix,
+            swap,
+            flip_rows,
+            flip_cols,
+            rows,
+            cols,
+        }
+    }
+
+    fn source(&self, row: usize, col: usize) -> (usize, usize) {
+        let row = if self.flip_rows {
+            self.rows - 1 - row
+        } else {
+            row
+        };
+        let col = if self.flip_cols {
+            self.cols - 1 - col
+        } else {
+            col
+        };
+        if self.swap { (col, row) } else { (row, col) }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        (row < self.rows && col < self.cols).then(|| &self.matrix[self.source(row, col)])
+    }
+
+    pub fn transposed(self) -> Self {
+        Self::new(self.matrix, !self.swap, self.flip_cols, self.flip_rows)
+    }
+
+    pub fn flipped_vertical(self) -> Self {
+        Self::new(self.matrix, self.swap, !self.flip_rows, self.flip_cols)
+    }
+
+    pub fn flipped_horizontal(self) -> Self {
+        Self::new(self.matrix, self.swap, self.flip_rows, !self.flip_cols)
+    }
+
+    pub fn rotated_cw(self) -> Self {
+        self.transposed().flipped_horizontal()
+    }
+
+    pub fn rotated_ccw(self) -> Self {
+        self.transposed().flipped_vertical()
+    }
+
+    pub fn rotated_180(self) -> Self {
+        self.flipped_vertical().flipped_horizontal()
+    }
+
+    pub fn row(self, row: usize) -> impl Iterator<Item = &'a T> {
+        assert!(row < self.rows, "row {row} out of bounds");
+        (0..self.cols).map(move |col| &self.matrix[self.source(row, col)])
+    }
+
+    pub fn column(self, col: usize) -> impl Iterator<Item = &'a T> {
+        assert!(col < self.cols, "column {col} out of bounds");
+        (0..self.rows).map(move |row| &self.matrix[self.source(row, col)])
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    pub fn to_matrix(self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let data = self.iter_coords().map(|pos| self[pos].clone()).collect();
+        Matrix::from_vec(self.rows, self.cols, data)
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for View<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("index ({row}, {col}) out of bounds"))
+    }
+}
+
+impl<T: Clone> From<View<'_, T>> for Matrix<T> {
+    fn from(view: View<'_, T>) -> Self {
+        view.to_matrix()
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn view(&self) -> View<'_, T> {
+        View::new(self, false, false, false)
+    }
+
+    pub fn transposed(&self) -> View<'_, T> {
+        self.view().transposed()
+    }
+
+    pub fn rotated_cw(&self) -> View<'_, T> {
+        self.view().rotated_cw()
+    }
+
+    pub fn rotated_ccw(&self) -> View<'_, T> {
+        self.view().rotated_ccw()
+    }
+
+    pub fn rotated_180(&self) -> View<'_, T> {
+        self.view().rotated_180()
+    }
+
+    pub fn flipped_vertical(&self) -> View<'_, T> {
+        self.view().flipped_vertical()
+    }
+
+    pub fn flipped_horizontal(&self) -> View<'_, T> {
+        self.view().flipped_horizontal()
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {col} out of bounds");
+        (0..self.rows).map(move |row| &self.data[row * self.cols + col])
+    }
+
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(move |col| self.column(col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_and_columns_of_empty_matrices() {
+        let no_rows: Matrix<u8> = Matrix::from_vec(0, 3, Vec::new());
+        assert_eq!(no_rows.iter_rows().count(), 0);
+        assert_eq!(no_rows.column(2).count(), 0);
+        assert_eq!(no_rows.iter_cols().count(), 3);
+
+        let no_cols: Matrix<u8> = Matrix::from_vec(3, 0, Vec::new());
+        assert_eq!(
+            no_cols.iter_rows().collect::<Vec<_>>(),
+            vec![&[] as &[u8]; 3]
+        );
+        assert_eq!(no_cols.iter_cols().count(), 0);
+    }
+
+    #[test]
+    fn column_steps_through_rows() {
+        let matrix = Matrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        assert_eq!(matrix.column(1).copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+        assert_eq!(
+            matrix.transposed().row(1).copied().collect::<Vec<_>>(),
+            vec![2, 4, 6]
+        );
+    }
+}
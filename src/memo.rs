@@ -0,0 +1,146 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    pub fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    pub fn hit_rate(&self) -> f64 {
+        if self.lookups() == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.lookups() as f64
+        }
+    }
+}
+
+impl Add for CacheStats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        CacheStats {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+        }
+    }
+}
+
+thread_local! {
+    static RECORDED: Cell<Option<CacheStats>> = const { Cell::new(None) };
+}
+
+/// Takes the combined statistics of every memo dropped on this thread since the last call.
+pub fn take_recorded() -> Option<CacheStats> {
+    RECORDED.take()
+}
+
+enum Backing<K, V> {
+    Hashed(HashMap<K, V>),
+    Dense {
+        values: Vec<Option<V>>,
+        index: Box<dyn Fn(&K) -> usize>,
+    },
+}
+
+/// A cache for recursive computations. Statistics are recorded when it is dropped, so
+/// the runner can attribute them to the part that used it.
+pub struct Memo<K, V> {
+    backing: Backing<K, V>,
+    stats: CacheStats,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            backing: Backing::Hashed(HashMap::new()),
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// A cache backed by a vector of `size` slots, for keys that map to small integers.
+    pub fn dense(size: usize, index: impl Fn(&K) -> usize + 'static) -> Self {
+        Self {
+            backing: Backing::Dense {
+                values: vec![None; size],
+                index: Box::new(index),
+            },
+            stats: CacheStats::default(),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match &self.backing {
+            Backing::Hashed(map) => map.get(key),
+            Backing::Dense { values, index } => values[index(key)].as_ref(),
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        match &mut self.backing {
+            Backing::Hashed(map) => {
+                map.insert(key, value);
+            }
+            Backing::Dense { values, index } => values[index(&key)] = Some(value),
+        }
+    }
+
+    /// Returns the cached value for `key`, or computes and caches it. `compute` receives
+    /// the memo itself so it can recurse on other keys.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.get(&key).cloned() {
+            self.stats.hits += 1;
+            return value;
+        }
+
+        self.stats.misses += 1;
+        let value = compute(self, &key);
+        self.insert(key, value.clone());
+        value
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        match &self.backing {
+            Backing::Hashed(map) => map.len(),
+            Backing::Dense { values, .. } => values.iter().filter(|v| v.is_some()).count(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[allow(dead_code)]
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        if self.stats.lookups() > 0 {
+            let stats = self.stats;
+            RECORDED.set(Some(
+                RECORDED.get().map_or(stats, |recorded| recorded + stats),
+            ));
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// A blank-line-separated chunk of the input, remembering the 1-based line it starts on
+/// so errors inside it can still point at the right line.
+#[derive(Debug, Clone, Copy)]
+pub struct Block<'a> {
+    pub text: &'a str,
+    pub line: usize,
+}
+
+impl<'a> Block<'a> {
+    pub fn lines<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Into<ParseError>,
+    {
+        self.lines_with(str::parse)
+    }
+
+    pub fn lines_with<T, E: Into<ParseError>>(
+        &self,
+        mut f: impl FnMut(&'a str) -> Result<T, E>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| f(line).map_err(|e| e.into().at_line(self.line + i, line)))
+            .collect()
+    }
+}
+
+pub fn lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    lines_with(input, str::parse)
+}
+
+/// Maps every line through `f`, attaching the line number and text to any error.
+pub fn lines_with<'a, T, E: Into<ParseError>>(
+    input: &'a str,
+    f: impl FnMut(&'a str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    Block {
+        text: input,
+        line: 1,
+    }
+    .lines_with(f)
+}
+
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        let blank = line.trim().is_empty();
+        match (start, blank) {
+            (None, false) => start = Some((offset, i + 1)),
+            (Some((from, line)), true) => {
+                blocks.push(Block {
+                    text: &input[from..offset],
+                    line,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    if let Some((from, line)) = start {
+        blocks.push(Block {
+            text: &input[from..],
+            line,
+        });
+    }
+
+    blocks
+}
+
+/// Every signed integer in `line`, ignoring whatever separates them.
+#[allow(dead_code)]
+pub fn ints(line: &str) -> Vec<i64> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if bytes[i].is_ascii_digit() || negative {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if let Ok(n) = line[start..i].parse() {
+                numbers.push(n);
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    numbers
+}
+
+pub fn list<T>(s: &str, delimiter: char) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    let mut offset = 0;
+    s.split(delimiter)
+        .map(|item| {
+            let start = offset + item.len() - item.trim_start().len();
+            offset += item.len() + delimiter.len_utf8();
+
+            let item = item.trim();
+            item.parse().map_err(|e: T::Err| {
+                let e = e.into();
+                ParseError {
+                    cause: format!("Invalid list item '{item}': {}", e.cause),
+                    ..e
+                }
+                .shifted(start)
+            })
+        })
+        .collect()
+}
+
+#[allow(dead_code)]
+pub fn key_value<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(separator)
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| ParseError::new(format!("Missing '{separator}'")))
+}
+
+#[allow(dead_code)]
+pub fn key_values<'a>(
+    input: &'a str,
+    separator: &str,
+) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    lines_with(input, |line| key_value(line, separator))
+}
+
+/// The contents of every top-level `open`..`close` group in `s`, in order, along with
+/// the byte offset at which each group's contents start.
+pub fn bracketed(s: &str, open: char, close: char) -> Result<Vec<(usize, &str)>, ParseError> {
+    let mut groups = Vec::new();
+    let mut start = None;
+    let mut depth = 0usize;
+
+    for (col, c) in s.char_indices() {
+        if c == open {
+            if depth == 0 {
+                start = Some(col + c.len_utf8());
+            }
+            depth += 1;
+        } else if c == close {
+            if depth == 0 {
+                return Err(ParseError::new(format!("Unmatched '{close}'")).at_column(col + 1));
+            }
+            depth -= 1;
+            if depth == 0
+                && let Some(from) = start.take()
+            {
+                groups.push((from, &s[from..col]));
+            }
+        }
+    }
+
+    match start {
+        Some(from) => Err(ParseError::new(format!("Unclosed '{open}'")).at_column(from)),
+        None => Ok(groups),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_keeps_signs_only_before_digits() {
+        assert_eq!(ints("p=-3,14 v=7--2 x-y 42"), vec![-3, 14, 7, -2, 42]);
+        assert!(ints("no numbers - here").is_empty());
+    }
+
+    #[test]
+    fn key_values_trims_and_locates_errors() {
+        assert_eq!(
+            key_values("a: 1\n b :two words\n", ":").unwrap(),
+            vec![("a", "1"), ("b", "two words")]
+        );
+
+        let e = key_values("a: 1\nb 2", ":").unwrap_err();
+        assert_eq!(e.cause, "Missing ':'");
+        assert_eq!(e.line, Some(2));
+    }
+}
@@ -0,0 +1,112 @@
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::error::ParseError;
+use crate::parse;
+
+/// An integer point (or vector) in `N` dimensions, parsed from comma-separated coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+
+impl<const N: usize> Point<N> {
+    #[allow(dead_code)]
+    pub const ORIGIN: Self = Point([0; N]);
+
+    pub fn squared_euclidean(&self, other: &Self) -> i64 {
+        (0..N).map(|i| (self.0[i] - other.0[i]).pow(2)).sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn euclidean(&self, other: &Self) -> f64 {
+        (self.squared_euclidean(other) as f64).sqrt()
+    }
+
+    #[allow(dead_code)]
+    pub fn manhattan(&self, other: &Self) -> i64 {
+        (0..N).map(|i| (self.0[i] - other.0[i]).abs()).sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn chebyshev(&self, other: &Self) -> i64 {
+        (0..N)
+            .map(|i| (self.0[i] - other.0[i]).abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[allow(dead_code)]
+    pub fn dot(&self, other: &Self) -> i64 {
+        (0..N).map(|i| self.0[i] * other.0[i]).sum()
+    }
+}
+
+impl Point<3> {
+    #[allow(dead_code)]
+    pub fn cross(&self, other: &Self) -> Self {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
+        Point([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, scale: i64) -> Self {
+        Point(self.0.map(|c| c * scale))
+    }
+}
+
+impl<const N: usize> FromStr for Point<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = parse::list::<i64>(s, ',')?;
+        let found = coords.len();
+        coords
+            .try_into()
+            .map(Point)
+            .map_err(|_| ParseError::new(format!("Expected {N} coordinates, found {found}")))
+    }
+}
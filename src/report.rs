@@ -1,24 +1,100 @@
 use crate::days::DayResult;
-use comfy_table::Table;
+use crate::error::ParseError;
+use comfy_table::{Cell, Color, Table};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::fs;
+use std::io::IsTerminal;
 use std::ops::RangeInclusive;
+use std::path::Path;
 use std::time::Duration;
 
+#[derive(Clone, Copy)]
+pub struct Colors {
+    pub enabled: bool,
+    pub fast: Duration,
+    pub slow: Duration,
+}
+
+impl Colors {
+    pub fn auto() -> bool {
+        std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && std::io::stdout().is_terminal()
+    }
+
+    fn duration(&self, duration: Duration) -> Color {
+        if duration < self.fast {
+            Color::Green
+        } else if duration < self.slow {
+            Color::Yellow
+        } else {
+            Color::Red
+        }
+    }
+
+    fn stars(&self, stars: usize) -> Color {
+        match stars {
+            2 => Color::Green,
+            1 => Color::Yellow,
+            _ => Color::Red,
+        }
+    }
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Self {
+            enabled: Self::auto(),
+            fast: Duration::from_millis(1),
+            slow: Duration::from_millis(100),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct Report {
     pub results: Vec<DayResult>,
+    #[serde(skip)]
+    pub errors: Vec<ParseError>,
+    #[serde(skip)]
+    pub colors: Colors,
 }
 
 impl Report {
     pub fn from_day_range(days: RangeInclusive<u8>) -> Self {
-        let results = days.filter_map(DayResult::run).collect();
-        Self { results }
+        let mut results = Vec::new();
+        let mut errors = Vec::new();
+
+        for outcome in days.filter_map(DayResult::run) {
+            match outcome {
+                Ok(result) => results.push(result),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        Self {
+            results,
+            errors,
+            colors: Colors::default(),
+        }
     }
 
     pub fn total_time(&self) -> Duration {
         self.results.iter().map(|r| r.total_time).sum()
     }
 
-    fn format_duration(duration: Duration) -> String {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        serde_json::from_str(&json).map_err(|e| format!("Invalid report {}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Could not serialize report: {e}"))?;
+        fs::write(path, json).map_err(|e| format!("Could not write {}: {e}", path.display()))
+    }
+
+    pub fn format_duration(duration: Duration) -> String {
         let micros = duration.as_micros();
         if micros < 1_000 {
             format!("{} µs", micros)
@@ -44,9 +120,73 @@ impl Report {
     }
 }
 
+impl Report {
+    fn duration_cell(&self, duration: Option<Duration>) -> Cell {
+        match duration {
+            Some(duration) => {
+                Cell::new(Self::format_duration(duration)).fg(self.colors.duration(duration))
+            }
+            None => Cell::new("-").fg(Color::DarkGrey),
+        }
+    }
+
+    fn answer_cell(answer: Option<&str>) -> Cell {
+        match answer {
+            Some(answer) => Cell::new(answer),
+            None => Cell::new("-").fg(Color::Red),
+        }
+    }
+
+    fn stars_cell(&self, stars: usize) -> Cell {
+        Cell::new(Self::format_stars(stars)).fg(self.colors.stars(stars))
+    }
+}
+
+impl Report {
+    fn fmt_cache(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<_> = self
+            .results
+            .iter()
+            .flat_map(|result| {
+                [1, 2].into_iter().filter_map(move |part| {
+                    result.cache(part).map(|stats| (result.day, part, stats))
+                })
+            })
+            .collect();
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        if self.colors.enabled {
+            table.enforce_styling();
+        } else {
+            table.force_no_tty();
+        }
+        table.set_header(vec!["Day", "Part", "Cache Hits", "Misses", "Hit Rate"]);
+
+        for (day, part, stats) in rows {
+            table.add_row(vec![
+                Cell::new(format!("{:02}", day)),
+                Cell::new(part),
+                Cell::new(stats.hits),
+                Cell::new(stats.misses),
+                Cell::new(format!("{:.1}%", stats.hit_rate() * 100.0)),
+            ]);
+        }
+
+        writeln!(f, "\n{}", table)
+    }
+}
+
 impl Display for Report {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut table = Table::new();
+        if self.colors.enabled {
+            table.enforce_styling();
+        } else {
+            table.force_no_tty();
+        }
         table.set_header(vec![
             "Day",
             "Stars",
@@ -60,20 +200,14 @@ impl Display for Report {
 
         for result in &self.results {
             table.add_row(vec![
-                format!("{:02}", result.day),
-                Self::format_stars(Self::count_stars(result)),
-                Self::format_duration(result.input_load_time),
-                result.part1_answer.as_deref().unwrap_or("-").to_string(),
-                result
-                    .part1_time
-                    .map(Self::format_duration)
-                    .unwrap_or("-".to_string()),
-                result.part2_answer.as_deref().unwrap_or("-").to_string(),
-                result
-                    .part2_time
-                    .map(Self::format_duration)
-                    .unwrap_or("-".to_string()),
-                Self::format_duration(result.total_time),
+                Cell::new(format!("{:02}", result.day)),
+                self.stars_cell(Self::count_stars(result)),
+                self.duration_cell(Some(result.input_load_time)),
+                Self::answer_cell(result.part1_answer.as_deref()),
+                self.duration_cell(result.part1_time),
+                Self::answer_cell(result.part2_answer.as_deref()),
+                self.duration_cell(result.part2_time),
+                self.duration_cell(Some(result.total_time)),
             ]);
         }
 
@@ -83,6 +217,8 @@ impl Display for Report {
             "\nTotal: {} stars | {}",
             self.total_stars(),
             Self::format_duration(self.total_time())
-        )
+        )?;
+
+        self.fmt_cache(f)
     }
 }
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint sets over `0..n` with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = x;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `x` and `y`, returning `false` if they were already one.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let mut root_x = self.find(x);
+        let mut root_y = self.find(y);
+
+        if root_x == root_y {
+            return false;
+        }
+
+        if self.size[root_x] < self.size[root_y] {
+            std::mem::swap(&mut root_x, &mut root_y);
+        }
+
+        self.parent[root_y] = root_x;
+        self.size[root_x] += self.size[root_y];
+        self.components -= 1;
+        true
+    }
+
+    #[allow(dead_code)]
+    pub fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    #[allow(dead_code)]
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Sizes of all components, read directly off the roots.
+    pub fn sizes(&self) -> Vec<usize> {
+        (0..self.parent.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|i| self.size[i])
+            .collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root = vec![usize::MAX; self.parent.len()];
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+
+        for i in 0..self.parent.len() {
+            let root = self.find(i);
+            if index_of_root[root] == usize::MAX {
+                index_of_root[root] = components.len();
+                components.push(Vec::with_capacity(self.size[root]));
+            }
+            components[index_of_root[root]].push(i);
+        }
+
+        components
+    }
+}
+
+/// A [`UnionFind`] over arbitrary hashable items, assigning indices on first sight.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct KeyedUnionFind<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    sets: UnionFind,
+}
+
+#[allow(dead_code)]
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+            sets: UnionFind::new(0),
+        }
+    }
+
+    fn index(&mut self, key: &K) -> usize {
+        if let Some(&index) = self.indices.get(key) {
+            return index;
+        }
+
+        let index = self.keys.len();
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key.clone());
+        self.sets.parent.push(index);
+        self.sets.size.push(1);
+        self.sets.components += 1;
+        index
+    }
+
+    pub fn insert(&mut self, key: &K) {
+        self.index(key);
+    }
+
+    pub fn find(&mut self, key: &K) -> &K {
+        let index = self.index(key);
+        let root = self.sets.find(index);
+        &self.keys[root]
+    }
+
+    pub fn union(&mut self, x: &K, y: &K) -> bool {
+        let x = self.index(x);
+        let y = self.index(y);
+        self.sets.union(x, y)
+    }
+
+    pub fn connected(&mut self, x: &K, y: &K) -> bool {
+        let x = self.index(x);
+        let y = self.index(y);
+        self.sets.connected(x, y)
+    }
+
+    pub fn size_of(&mut self, key: &K) -> usize {
+        let index = self.index(key);
+        self.sets.size_of(index)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    pub fn sizes(&self) -> Vec<usize> {
+        self.sets.sizes()
+    }
+
+    pub fn components(&mut self) -> Vec<Vec<&K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|component| component.into_iter().map(|i| &self.keys[i]).collect())
+            .collect()
+    }
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyed_union_find_groups_keys() {
+        let mut uf = KeyedUnionFind::new();
+        for key in ["a", "b", "c", "d"] {
+            uf.insert(&key);
+        }
+
+        assert!(uf.union(&"a", &"b"));
+        assert!(uf.union(&"c", &"b"));
+        assert!(!uf.union(&"a", &"c"));
+        assert!(uf.connected(&"a", &"c"));
+        assert!(!uf.connected(&"a", &"d"));
+        assert_eq!(uf.size_of(&"c"), 3);
+        assert_eq!(uf.component_count(), 2);
+
+        let mut sizes = uf.sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 3]);
+
+        let mut components: Vec<Vec<&str>> = uf
+            .components()
+            .into_iter()
+            .map(|c| {
+                let mut c: Vec<&str> = c.into_iter().copied().collect();
+                c.sort();
+                c
+            })
+            .collect();
+        components.sort();
+        assert_eq!(components, vec![vec!["a", "b", "c"], vec!["d"]]);
+    }
+
+    #[test]
+    fn keyed_union_find_inserts_on_union() {
+        let mut uf = KeyedUnionFind::new();
+        uf.union(&(0, 0), &(0, 1));
+        assert_eq!(uf.component_count(), 1);
+        let root = *uf.find(&(0, 1));
+        assert_eq!(*uf.find(&(0, 0)), root);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::days::DayResult;
+use std::fs;
+
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unverified,
+    Skipped,
+}
+
+pub fn read_expected(day: u8, part: u8) -> Option<String> {
+    let answers_path = format!("answers/day{:02}.txt", day);
+    let answers = fs::read_to_string(answers_path).ok()?;
+    answers
+        .lines()
+        .nth(part as usize - 1)
+        .map(str::trim)
+        .filter(|answer| !answer.is_empty())
+        .map(str::to_string)
+}
+
+impl Status {
+    pub fn of(result: &DayResult, part: u8) -> Self {
+        let (answer, _) = result.part(part);
+        match (answer, read_expected(result.day, part)) {
+            (None, _) => Status::Skipped,
+            (Some(_), None) => Status::Unverified,
+            (Some(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Some(_), Some(expected)) => Status::Fail { expected },
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "fail",
+            Status::Unverified => "unverified",
+            Status::Skipped => "skipped",
+        }
+    }
+}